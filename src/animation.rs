@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+use crate::MazeError;
+use crate::raster::{Rgb, RgbImage};
+
+
+// Largest number of pixels along each side of a GIF frame
+pub const GIF_MAX_SIZE: usize = u16::MAX as usize;
+
+
+// ----------------------------------------------------------------
+// Sequence of bitmap images (e.g. the intermediate steps of the generation and solving processes)...
+// ...to be encoded as an animated GIF, each frame being shown for "frame_delay" milliseconds
+// All the frames are expected to have the same size
+// ----------------------------------------------------------------
+#[derive(Debug, Clone)]
+pub struct Animation {
+  pub frame_delay: u32,
+  pub frames: Vec<RgbImage>
+}
+
+impl Animation {
+
+  pub fn new(frame_delay: u32) -> Animation {
+    Animation{frame_delay, frames: vec![]}
+  }
+
+  pub fn add_frame(&mut self, frame: RgbImage) {
+    self.frames.push(frame);
+  }
+
+
+  // ----------------------------------------------------------------
+  // Encode the frames as an animated GIF, looping forever
+  // The last frame is held ten times longer, so the final state can be seen before the animation starts over
+  // ----------------------------------------------------------------
+  pub fn write_gif<W: Write>(&self, writer: W) -> Result<(), MazeError> {
+
+    for frame in self.frames.iter() {
+      frame.check_size(GIF_MAX_SIZE)?;
+    }
+    let (width, height) = match self.frames.first() {
+      Some(frame) => (frame.width as u16, frame.height as u16),
+      None => (1, 1)
+    };
+    let mut encoder = gif::Encoder::new(writer, width, height, &[])?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    // The GIF format counts the delay in hundredths of a second
+    let delay = (self.frame_delay/10).min(u16::MAX as u32) as u16;
+    for (idx, image) in self.frames.iter().enumerate() {
+      let mut frame = get_indexed_frame(image);
+      frame.delay = if idx +1 == self.frames.len() {delay.saturating_mul(10)} else {delay};
+      encoder.write_frame(&frame)?;
+    }
+    Ok(())
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Convert a bitmap image into a GIF frame with a local palette (the image fitting in a GIF frame)
+// Mazes are drawn with a handful of colors only, so the palette is exact (no color quantization needed)...
+// ...unless the image holds more than the 256 colors allowed by the GIF format
+// ----------------------------------------------------------------
+fn get_indexed_frame(image: &RgbImage) -> gif::Frame<'static> {
+
+  let mut palette: Vec<u8> = vec![];
+  let mut color_indices: HashMap<Rgb, u8> = HashMap::new();
+  let mut indices = Vec::with_capacity(image.width*image.height);
+  for pixel in image.pixels.chunks_exact(3) {
+    let color: Rgb = [pixel[0], pixel[1], pixel[2]];
+    let nb_colors = color_indices.len();
+    if nb_colors == 256 && !color_indices.contains_key(&color) {
+      return gif::Frame::from_rgb_speed(image.width as u16, image.height as u16, &image.pixels, 10);
+    }
+    let index = *color_indices.entry(color).or_insert_with(|| {
+      palette.extend_from_slice(&color);
+      nb_colors as u8
+    });
+    indices.push(index);
+  }
+  gif::Frame::from_palette_pixels(image.width as u16, image.height as u16, indices, palette, None)
+}
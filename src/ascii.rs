@@ -0,0 +1,602 @@
+use std::io::Write;
+
+use ndarray::{Array2, ArrayView1};
+use colored::{ColoredString, Colorize};
+
+use crate::{Direction, Gate, Maze, MazeError, Openings, Pos, Theme};
+use crate::box_drawing;
+use crate::distance::{get_distance_decile, get_gradient_color, get_max_distance};
+use crate::solving::Solution;
+use crate::theme::Glyph;
+
+
+// The purpose using these integer codes is that they can be stored in an array, and link to the corresponding string
+pub static WAL_CODE: u8 = 100;      // wall
+pub static NOD_CODE: u8 = 101;      // generic node
+pub static OPN_CODE: u8 = 102;      // opening
+pub static ALV_CODE: u8 = 103;      // node in the alive region
+pub static NAR_CODE: u8 = 104;      // node in the narrow band
+pub static FAR_CODE: u8 = 105;      // node in the far away region
+pub static ENT_CODE: u8 = 106;      // entrance, inside the maze
+pub static GOL_CODE: u8 = 107;      // goal, inside the maze
+pub static PATH_L2R_CODE: u8 = 200; // node corresponding to a "left to right" move of the shortest path
+pub static PATH_R2L_CODE: u8 = 201; // node corresponding to a "right to left" move of the shortest path
+pub static PATH_U2D_CODE: u8 = 202; // node corresponding to an "up to down" move of the shortest path
+pub static PATH_D2U_CODE: u8 = 203; // node corresponding to a "down to up" move of the shortest path
+pub static HEX_1_CODE: u8 = 1;      // hexadecimal code for a possible move
+pub static HEX_2_CODE: u8 = 2;      // hexadecimal code for a possible move
+pub static HEX_3_CODE: u8 = 3;      // hexadecimal code for a possible move
+pub static HEX_4_CODE: u8 = 4;      // hexadecimal code for a possible move
+pub static HEX_5_CODE: u8 = 5;      // hexadecimal code for a possible move
+pub static HEX_6_CODE: u8 = 6;      // hexadecimal code for a possible move
+pub static HEX_7_CODE: u8 = 7;      // hexadecimal code for a possible move
+pub static HEX_8_CODE: u8 = 8;      // hexadecimal code for a possible move
+pub static HEX_9_CODE: u8 = 9;      // hexadecimal code for a possible move
+pub static HEX_A_CODE: u8 = 10;     // hexadecimal code for a possible move
+pub static HEX_B_CODE: u8 = 11;     // hexadecimal code for a possible move
+pub static HEX_C_CODE: u8 = 12;     // hexadecimal code for a possible move
+pub static HEX_D_CODE: u8 = 13;     // hexadecimal code for a possible move
+pub static HEX_E_CODE: u8 = 14;     // hexadecimal code for a possible move
+pub static HEX_F_CODE: u8 = 15;     // hexadecimal code for a possible move
+
+
+// ----------------------------------------------------------------
+// Routine to display the maze in ascii-art, written to the console ("std::io::stdout()") or to any other output
+// The entrance and the goal are drawn in the outer walls they open on, if any (see "placement::Gate")
+// ----------------------------------------------------------------
+pub fn write_maze_in_ascii(
+  out: &mut impl Write, maze: &Array2<u8>, entrance: &Gate, goal: &Gate, symbol_code: &Array2<u8>,
+  theme: &Theme) -> Result<(), MazeError> {
+  let mut symbs = Vec::with_capacity(symbol_code.nrows());
+  for (y, symbol_row) in symbol_code.rows().into_iter().enumerate() {
+    symbs.push(paint_symbol_row(symbol_row, y, theme)?);
+  }
+  write_painted_maze(out, maze, entrance, goal, symbs, theme)
+}
+
+
+// ----------------------------------------------------------------
+// Routine to display the maze in ascii-art, each node being drawn with a symbol that has already been painted...
+// ...row by row (e.g. on a colored background, see "write_distance_map")
+// ----------------------------------------------------------------
+pub(crate) fn write_painted_maze(
+  out: &mut impl Write, maze: &Array2<u8>, entrance: &Gate, goal: &Gate, symbs: Vec<Vec<ColoredString>>,
+  theme: &Theme) -> Result<(), MazeError> {
+
+  if theme.thin_walls {
+    return box_drawing::write_painted_maze_in_box_drawing(out, maze, entrance, goal, symbs, theme);
+  }
+  let (height, width) = maze.dim();
+  for (y, symb_row) in symbs.into_iter().enumerate().take(height) {
+    write_painted_row_in_ascii(out, maze.row(y), y, entrance, goal, symb_row, theme)?;
+  }
+  write_maze_bottom_in_ascii(out, width, height, entrance, goal, theme)
+}
+
+
+// ----------------------------------------------------------------
+// Routine to display the "y"-th row of the maze in ascii-art (two console lines per row)
+// Rows can thus be printed one at a time, without the whole maze having to be stored
+// The row is checked beforehand, so nothing is printed if one of its nodes cannot be drawn
+// ----------------------------------------------------------------
+pub fn write_maze_row_in_ascii(
+  out: &mut impl Write, maze_row: ArrayView1<u8>, y: usize, entrance: &Gate, goal: &Gate, symbol_row: ArrayView1<u8>,
+  theme: &Theme) -> Result<(), MazeError> {
+  let symbs = paint_symbol_row(symbol_row, y, theme)?;
+  write_painted_row_in_ascii(out, maze_row, y, entrance, goal, symbs, theme)
+}
+
+
+// ----------------------------------------------------------------
+// Routine to display the "y"-th row of the maze in ascii-art, each node being drawn with an already-painted symbol
+// ----------------------------------------------------------------
+fn write_painted_row_in_ascii(
+  out: &mut impl Write, maze_row: ArrayView1<u8>, y: usize, entrance: &Gate, goal: &Gate, symbs: Vec<ColoredString>,
+  theme: &Theme) -> Result<(), MazeError> {
+
+  // The code "0" is only legit for a node that has not been connected yet, while the maze is being generated
+  let mut row_openings = Vec::with_capacity(maze_row.len());
+  for (x, &code) in maze_row.iter().enumerate() {
+    row_openings.push(Openings::from_bits(code).ok_or(MazeError::InvalidCode{pos: Pos{x, y}, code})?);
+  }
+
+  // Top half of the node
+  for (x, &openings) in row_openings.iter().enumerate() {
+    // Check for entrance or goal
+    let wall_or_gate = get_wall_or_gate(x, y, Direction::Up, entrance, goal, theme);
+    write_ascii_node_top_half(out, openings, wall_or_gate, theme)?;
+  }
+  writeln!(out, "{}", theme.wall.paint())?;
+
+  // Bottom half of the node
+  let width = row_openings.len();
+  for (x, (&openings, symb)) in row_openings.iter().zip(symbs).enumerate() {
+    // Check for entrance or goal
+    let wall_or_gate = get_wall_or_gate(x, y, Direction::Left, entrance, goal, theme);
+    write_ascii_node_bot_half(out, openings, wall_or_gate, symb, theme)?;
+  }
+
+  // Check for entrance or goal
+  let wall_or_gate = get_wall_or_gate(width.saturating_sub(1), y, Direction::Right, entrance, goal, theme);
+  writeln!(out, "{}", wall_or_gate.paint())?;
+  Ok(())
+}
+
+
+// ----------------------------------------------------------------
+// Symbol of the outer wall on a given side of the node ("x", "y"): the entrance or the goal if they open on it...
+// ...and a wall otherwise
+// ----------------------------------------------------------------
+pub(crate) fn get_wall_or_gate<'a>(
+  x: usize, y: usize, side: Direction, entrance: &Gate, goal: &Gate, theme: &'a Theme) -> &'a Glyph {
+  if entrance.opens(x, y, side) {
+    &theme.entrance
+  } else if goal.opens(x, y, side) {
+    &theme.goal
+  } else {
+    &theme.wall
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Symbols of the "codenames" of the "y"-th row, painted in their colors
+// ----------------------------------------------------------------
+pub(crate) fn paint_symbol_row(
+  symbol_row: ArrayView1<u8>, y: usize, theme: &Theme) -> Result<Vec<ColoredString>, MazeError> {
+  symbol_row.iter().enumerate()
+    .map(|(x, &symbol_code)| theme.paint(symbol_code).ok_or(MazeError::InvalidSymbol{pos: Pos{x, y}, symbol_code}))
+    .collect()
+}
+
+
+// ----------------------------------------------------------------
+// Routine to display the closing line at the bottom of the maze in ascii-art, below its last row ("height" -1)
+// ----------------------------------------------------------------
+pub fn write_maze_bottom_in_ascii(
+  out: &mut impl Write, width: usize, height: usize, entrance: &Gate, goal: &Gate,
+  theme: &Theme) -> Result<(), MazeError> {
+
+  // Check for entrance or goal
+  for x in 0..width {
+    let wall_or_gate = get_wall_or_gate(x, height.saturating_sub(1), Direction::Down, entrance, goal, theme);
+    write!(out, "{}{}", theme.wall.paint(), wall_or_gate.paint())?;
+  }
+  write!(out, "{}\n\n", theme.wall.paint())?;
+  Ok(())
+}
+
+
+// ----------------------------------------------------------------
+// Routine to print the top-part of a given node
+// Nodes with hexa code (1, 2, 3, 4, 5, 6, 7) have their top side facing a wall, or the entrance or the goal
+// ----------------------------------------------------------------
+fn write_ascii_node_top_half(
+  out: &mut impl Write, openings: Openings, wall_or_gate: &Glyph, theme: &Theme) -> std::io::Result<()> {
+  if !openings.is_open(Direction::Up) {
+    write!(out, "{}{}", theme.wall.paint(), wall_or_gate.paint())
+  } else {
+    write!(out, "{}{}", theme.wall.paint(), theme.opening.paint())
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Routine to print the bottom part of a given node
+// Nodes with hexa code (1, 4, 5, 8, 9, C, D) have their left side facing a wall, or the entrance or the goal
+// ----------------------------------------------------------------
+fn write_ascii_node_bot_half(
+  out: &mut impl Write, openings: Openings, wall_or_gate: &Glyph, symb: ColoredString,
+  theme: &Theme) -> std::io::Result<()> {
+  if !openings.is_open(Direction::Left) {
+    write!(out, "{}{}", wall_or_gate.paint(), symb)
+  } else {
+    write!(out, "{}{}", theme.opening.paint(), symb)
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Prepare a lookup table of "codenames" so each node of the maze is associated with their corresponding ascii symbol
+// Here, the simple floorplan of the maze is displayed, therefore each node is represented by "theme.node"
+// ----------------------------------------------------------------
+pub fn get_codenames_for_naked_maze(width: usize, height: usize) -> Array2<u8> {
+  let mut codenames = Array2::<u8>::zeros((height, width));
+  for y in 0..height {
+    for x in 0..width {
+      codenames[[y, x]] = NOD_CODE;
+    }
+  }
+  codenames
+}
+
+
+// ----------------------------------------------------------------
+// Prepare a lookup table of "codenames" so each node of the maze is associated with their corresponding ascii symbol
+// Here, the floorplan of the maze is displayed, along with the entrance and the goal when they lie inside the maze...
+// ...(and thus open on no outer wall), drawn in their node by "theme.entrance" and "theme.goal"
+// ----------------------------------------------------------------
+pub fn get_codenames_for_floorplan(maze: &Maze) -> Array2<u8> {
+  let mut codenames = get_codenames_for_naked_maze(maze.width, maze.height);
+  for (gate, codename) in [(maze.entrance_gate(), ENT_CODE), (maze.goal_gate(), GOL_CODE)] {
+    if gate.side.is_none() {
+      codenames[[gate.pos.y, gate.pos.x]] = codename;
+    }
+  }
+  codenames
+}
+
+
+// ----------------------------------------------------------------
+// Prepare a lookup table of "codenames" so each node of the maze is associated with their corresponding ascii symbol
+// Here, the current state of the front propagation is displayed, using "theme.alive", "theme.narrow", or "theme.far"
+// ----------------------------------------------------------------
+pub fn get_codenames_for_alv_nar_far_regions(
+  alv_region: &Array2<bool>, nar_region: &Array2<bool>, far_region: &Array2<bool>) -> Result<Array2<u8>, MazeError> {
+  let (height, width) = alv_region.dim();
+  let mut codenames = Array2::<u8>::zeros((height, width));
+  for y in 0..height {
+    for x in 0..width {
+      codenames[[y, x]] = if alv_region[[y, x]] {
+        ALV_CODE
+      } else if nar_region[[y, x]] {
+        NAR_CODE
+      } else if far_region[[y, x]] {
+        FAR_CODE
+      } else {
+        return Err(MazeError::InvalidRegions{pos: Pos{x, y}});
+      };
+    }
+  }
+  Ok(codenames)
+}
+
+
+// ----------------------------------------------------------------
+// Prepare a lookup table of "codenames" so each node of the maze is associated with their corresponding ascii symbol
+// Here, the shortest path is displayed, each node of the path showing the direction of the move towards the next node
+// ----------------------------------------------------------------
+pub fn get_codenames_for_path(maze: &Maze, path: &[Pos]) -> Result<Array2<u8>, MazeError> {
+
+  // Initialize the backtracking array with the code-word for generic nodes
+  let mut codenames = get_codenames_for_naked_maze(maze.width, maze.height);
+
+  // The goal points towards the outside of the maze, through the outer wall it opens on (if any)
+  if let Some(pos_goal) = path.last() {
+    codenames[[pos_goal.y, pos_goal.x]] = match maze.goal_gate().side {
+      Some(Direction::Left) => PATH_R2L_CODE,
+      Some(Direction::Right) => PATH_L2R_CODE,
+      Some(Direction::Up) => PATH_D2U_CODE,
+      Some(Direction::Down) => PATH_U2D_CODE,
+      None => GOL_CODE
+    };
+  }
+
+  // Encode the path direction (either L2R:">", R2L:"<", U2D:"v", or D2U:"^") in the backtracking array
+  for step in path.windows(2) {
+    let (pos_prev, pos_current) = (&step[0], &step[1]);
+    codenames[[pos_prev.y, pos_prev.x]] = if pos_current.x == pos_prev.x +1 && pos_current.y == pos_prev.y {
+      PATH_L2R_CODE
+    } else if pos_prev.x > 0 && pos_current.x == pos_prev.x -1 && pos_current.y == pos_prev.y {
+      PATH_R2L_CODE
+    } else if pos_current.x == pos_prev.x && pos_current.y == pos_prev.y +1 {
+      PATH_U2D_CODE
+    } else if pos_prev.y > 0 && pos_current.x == pos_prev.x && pos_current.y == pos_prev.y -1 {
+      PATH_D2U_CODE
+    } else {
+      return Err(MazeError::InvalidPath{pos_a: *pos_prev, pos_b: *pos_current});
+    };
+  }
+  Ok(codenames)
+}
+
+
+// ----------------------------------------------------------------
+// Display an intermediate step of the maze generation
+// Nodes that have not been connected yet are drawn as fully-closed rooms
+// ----------------------------------------------------------------
+pub fn write_generation_step(
+  out: &mut impl Write, maze: &Array2<u8>, nb_iter_create: usize, theme: &Theme) -> Result<(), MazeError> {
+  writeln!(out, "[generation] iteration: {}", nb_iter_create)?;
+  let (height, width) = maze.dim();
+  // The entrance and goal are not displayed
+  write_maze_in_ascii(
+    out, maze, &Gate::hidden(), &Gate::hidden(), &get_codenames_for_naked_maze(width, height), theme)
+}
+
+
+// ----------------------------------------------------------------
+// Display an intermediate step of the front propagation
+// ----------------------------------------------------------------
+pub fn write_propagation_step(
+  out: &mut impl Write, maze: &Maze, nb_iter_solve: usize, alv_region: &Array2<bool>, nar_region: &Array2<bool>,
+  far_region: &Array2<bool>, theme: &Theme) -> Result<(), MazeError> {
+  let nb_nodes = maze.width*maze.height;
+  writeln!(out, "[propagation] iteration: {} | alive region: {}% | narrow band: {}% | far-away region: {}%",
+  nb_iter_solve,
+  100*count_nb_of_true(alv_region)/nb_nodes,
+  100*count_nb_of_true(nar_region)/nb_nodes,
+  100*count_nb_of_true(far_region)/nb_nodes)?;
+  let symbol_code = get_codenames_for_alv_nar_far_regions(alv_region, nar_region, far_region)?;
+  maze.render(out, &symbol_code, theme)
+}
+
+
+// ----------------------------------------------------------------
+// Display the last step of the front propagation
+// ----------------------------------------------------------------
+pub fn write_propagation_result(
+  out: &mut impl Write, maze: &Maze, solution: &Solution, theme: &Theme) -> Result<(), MazeError> {
+  write_propagation_summary(out, maze, solution)?;
+  let symbol_code = get_codenames_for_alv_nar_far_regions(
+    &solution.alv_region, &solution.nar_region, &solution.far_region)?;
+  maze.render(out, &symbol_code, theme)
+}
+
+
+// ----------------------------------------------------------------
+// Print the iteration statistics of the front propagation
+// The visited nodes are the ones that left the far away region: in a maze with loops, a node may become alive...
+// ...several times, so that the number of iterations can exceed the number of nodes
+// ----------------------------------------------------------------
+pub fn write_propagation_summary(out: &mut impl Write, maze: &Maze, solution: &Solution) -> Result<(), MazeError> {
+  let nb_visited = solution.far_region.iter().filter(|&&far| !far).count();
+  writeln!(
+    out, "[propagation] Maze solved with {} in {} iteration ({}% of the nodes have been visited)",
+    solution.solver, solution.nb_iter_solve, 100*nb_visited/(maze.width*maze.height))?;
+  Ok(())
+}
+
+
+// ----------------------------------------------------------------
+// Display the maze with the shortest path, and print the step-by-step solution
+// ----------------------------------------------------------------
+pub fn write_backtracking_result(
+  out: &mut impl Write, maze: &Maze, solution: &Solution, theme: &Theme) -> Result<(), MazeError> {
+  writeln!(out, "[backtracking] Path length: {}", solution.path_length)?;
+  maze.render(out, &get_codenames_for_path(maze, &solution.path)?, theme)?;
+
+  write!(out, "Path:")?;
+  for (idx, pos) in solution.path.iter().enumerate() {
+    if idx % 10 == 0 {
+      writeln!(out)?;
+    }
+    write!(out, "{}:({},{}) ", idx, pos.x, pos.y)?;
+  }
+  writeln!(out)?;
+  Ok(())
+}
+
+
+// ----------------------------------------------------------------
+// Display the distance of each node to a given node (e.g. the entrance) as a heat map, on a background going from...
+// ...blue (nearest nodes) to red (farthest nodes) in 24-bit colors
+// Each node also shows the tenth of the largest distance it lies at (0 to 9), so the map remains readable without...
+// ...colors, whereas the nodes that cannot be reached are drawn as generic nodes
+// ----------------------------------------------------------------
+pub fn write_distance_map(out: &mut impl Write, maze: &Maze, pos_origin: &Pos, theme: &Theme) -> Result<(), MazeError> {
+  let distances = maze.distances_from(pos_origin)?;
+  let max_distance = get_max_distance(&distances);
+  writeln!(
+    out, "[heat map] Distance to the node ({},{}): from 0 (blue) to {} moves (red)",
+    pos_origin.x, pos_origin.y, max_distance)?;
+  let symbs = distances.rows().into_iter()
+    .map(|distance_row| distance_row.iter().map(|distance| match distance {
+      Some(distance) => paint_distance(*distance, max_distance),
+      None => theme.node.paint()
+    }).collect())
+    .collect();
+  write_painted_maze(out, &maze.codes, &maze.entrance_gate(), &maze.goal_gate(), symbs, theme)
+}
+
+
+// ----------------------------------------------------------------
+// Tenth of the largest distance, on the color of the distance in the heat map (dark digit on light colors and...
+// ...light digit on dark colors)
+// ----------------------------------------------------------------
+fn paint_distance(distance: usize, max_distance: usize) -> ColoredString {
+  let [red, green, blue] = get_gradient_color(distance, max_distance);
+  let is_light = 299*red as u32 + 587*green as u32 + 114*blue as u32 > 128_000;
+  let digit = get_distance_decile(distance, max_distance).to_string();
+  let digit = if is_light {digit.truecolor(0, 0, 0)} else {digit.truecolor(255, 255, 255)};
+  digit.on_truecolor(red, green, blue)
+}
+
+
+// ----------------------------------------------------------------
+// Count the number of "True" in a boolean vector
+// ----------------------------------------------------------------
+fn count_nb_of_true(alv_nar_far_array: &Array2<bool>) -> usize {
+  let mut nb_of_true = 0;
+  for value in alv_nar_far_array.iter() {
+    if *value {
+      nb_of_true +=1;
+    }
+  }
+  nb_of_true
+}
+
+
+// ----------------------------------------------------------------
+// Read a maze printed in the console in ascii-art, with the symbols of any of the built-in themes
+// The first block of lines starting with a wall is parsed (so a whole printout can be given), color codes aside
+// The possible moves from each node are reconstructed from the walls in-between the nodes, and the orientation...
+// ...from the sides of the entrance and the goal, whereas the symbols of the nodes themselves are ignored (except...
+// ...for the entrance and the goal, when drawn in their node)
+// ----------------------------------------------------------------
+pub fn parse_maze_from_ascii(text: &str) -> Result<Maze, MazeError> {
+
+  // Walls may also be drawn as thin lines (see "box_drawing")
+  let is_wall = |c: char| is_wall_symbol(c) || is_box_drawing(c);
+
+  // Collect the characters of the first block of lines starting with a wall
+  let mut lines: Vec<Vec<char>> = text.lines()
+    .map(strip_color_codes)
+    .skip_while(|line| !line.starts_with(is_wall))
+    .take_while(|line| !line.trim().is_empty())
+    .map(|line| line.trim_end().chars().collect())
+    .collect();
+  if lines.is_empty() {
+    return Err(MazeError::Parse(String::from("no maze found (namely, no line starting with a wall)")));
+  }
+
+  // A line may end with a blank corner (e.g. in-between the entrance and the goal, both opening on the right side)...
+  // ...which is lost along with the trailing blanks: the lines are padded up to the length of the first one, whose...
+  // ...corners always hold a wall (the entrance and the goal being two distinct nodes)
+  let nb_columns = lines[0].len();
+  for line in lines.iter_mut() {
+    line.resize(line.len().max(nb_columns), ' ');
+  }
+
+  // Thin walls are printed in a compact layout, which starts with a box-drawing corner (see "box_drawing")
+  let (codes, entrance, goal) = if is_box_drawing(lines[0][0]) {
+    box_drawing::parse_maze_from_box_drawing(&lines)?
+  } else {
+    parse_maze_from_blocks(&lines)?
+  };
+  let (height, width) = codes.dim();
+
+  // The orientation tells which side the entrance and the goal open on, when they lie in a corner...
+  // ...and is otherwise given by the side of the entrance (top or bottom for a vertical maze), as far as possible
+  let is_vertical = matches!(entrance.side.or(goal.side), Some(Direction::Up | Direction::Down));
+  let Some(orientation) = [!is_vertical, is_vertical].into_iter().find(|&orientation| {
+    Gate::entrance(entrance.pos, width, height, orientation) == entrance &&
+      Gate::goal(goal.pos, width, height, orientation) == goal
+  }) else {
+    return Err(MazeError::Parse(String::from("the entrance and/or the goal open on unexpected sides")));
+  };
+  let (pos_entrance, pos_goal) = (entrance.pos, goal.pos);
+
+  let maze = Maze{
+    width, height, codes, pos_entrance, pos_goal, orientation, generator: String::from("unknown"), seed: None,
+    nb_iter_create: 0};
+  maze.check_consistency()?;
+  Ok(maze)
+}
+
+
+// ----------------------------------------------------------------
+// Read the possible moves from each node, along with the entrance and the goal, from the lines of a maze printed...
+// ...with one character per corner, per side, and per node (namely, two console lines per row)
+// ----------------------------------------------------------------
+fn parse_maze_from_blocks(lines: &[Vec<char>]) -> Result<(Array2<u8>, Gate, Gate), MazeError> {
+
+  let themes = Theme::builtins().map(|(_, theme)| theme);
+  let is_symbol = |c: char, role: fn(&Theme) -> &Glyph| themes.iter().any(|theme| role(theme).symbol.starts_with(c));
+  let is_entrance = |c: char| is_symbol(c, |theme| &theme.entrance);
+  let is_goal = |c: char| is_symbol(c, |theme| &theme.goal);
+
+  if lines.len() < 3 || lines.len().is_multiple_of(2) {
+    return Err(MazeError::Parse(
+      format!("{} lines found, whereas a maze is printed on an odd number of lines", lines.len())));
+  }
+  let nb_columns = lines[0].len();
+  if nb_columns < 3 || nb_columns.is_multiple_of(2) {
+    return Err(MazeError::Parse(
+      format!("{} characters found per line, whereas a maze is printed on an odd number", nb_columns)));
+  }
+  if let Some(y) = lines.iter().position(|line| line.len() != nb_columns) {
+    return Err(MazeError::Parse(format!("line {} has {} characters instead of {}", y, lines[y].len(), nb_columns)));
+  }
+  let (width, height) = ((nb_columns -1)/2, (lines.len() -1)/2);
+  let is_open = |row: usize, column: usize| lines[row][column] == ' ';
+
+  // The outer walls are closed, except for the entrance and the goal, when they open on them
+  let (mut entrance, mut goal) = (None, None);
+  for row in 0..lines.len() {
+    for column in 0..nb_columns {
+      let is_outer_wall = row == 0 || column == 0 || row +1 == lines.len() || column +1 == nb_columns;
+      let is_corner = row.is_multiple_of(2) && column.is_multiple_of(2);
+      let c = lines[row][column];
+      if is_outer_wall && !is_corner && (is_entrance(c) || is_goal(c)) {
+        let side = if row == 0 {
+          Direction::Up
+        } else if row +1 == lines.len() {
+          Direction::Down
+        } else if column == 0 {
+          Direction::Left
+        } else {
+          Direction::Right
+        };
+        let gate = Gate{pos: Pos{x: column.saturating_sub(1)/2, y: row.saturating_sub(1)/2}, side: Some(side)};
+        let (found, name) = if is_entrance(c) {(&mut entrance, "entrance")} else {(&mut goal, "goal")};
+        if found.replace(gate).is_some() {
+          return Err(MazeError::Parse(format!("the {} is drawn twice in the outer walls", name)));
+        }
+      } else if (is_outer_wall || is_corner) && !is_wall_symbol(c) {
+        return Err(MazeError::Parse(
+          format!("unexpected `{}` at line {}, column {} (expected a wall)", c, row, column)));
+      }
+    }
+  }
+
+  // Otherwise, they lie inside the maze, and are drawn in their node (see "get_codenames_for_floorplan")
+  let find_inside = |is_gate: &dyn Fn(char) -> bool| -> Option<Gate> {
+    let mut nodes = (0..height)
+      .flat_map(|y| (0..width).map(move |x| Pos{x, y}))
+      .filter(|pos| is_gate(lines[2*pos.y +1][2*pos.x +1]));
+    match (nodes.next(), nodes.next()) {
+      (Some(pos), None) => Some(Gate{pos, side: None}),
+      _ => None
+    }
+  };
+  let entrance = entrance.or_else(|| find_inside(&is_entrance));
+  let goal = goal.or_else(|| find_inside(&is_goal));
+  let (Some(entrance), Some(goal)) = (entrance, goal) else {
+    return Err(MazeError::Parse(String::from("the entrance and/or the goal are missing")));
+  };
+
+  // Reconstruct the possible moves from each node, the character in-between two neighbor nodes being either...
+  // ...a wall or an opening
+  let mut codes = Array2::<u8>::zeros((height, width));
+  for ((y, x), code) in codes.indexed_iter_mut() {
+    let mut openings = Openings::empty();
+    for dir in Direction::ALL {
+      if let Some(next) = dir.step(&Pos{x, y}).filter(|next| next.x < width && next.y < height) {
+        if is_open(y + next.y +1, x + next.x +1) {
+          openings.open(dir);
+        }
+      }
+    }
+    *code = openings.bits();
+  }
+  Ok((codes, entrance, goal))
+}
+
+
+// ----------------------------------------------------------------
+// Whether a character is the wall symbol of one of the built-in themes
+// ----------------------------------------------------------------
+fn is_wall_symbol(c: char) -> bool {
+  Theme::builtins().iter().any(|(_, theme)| theme.wall.symbol.starts_with(c))
+}
+
+
+// ----------------------------------------------------------------
+// Whether a character is a box-drawing character, as drawn by a theme with thin walls
+// ----------------------------------------------------------------
+fn is_box_drawing(c: char) -> bool {
+  ('\u{2500}'..='\u{257F}').contains(&c)
+}
+
+
+// ----------------------------------------------------------------
+// Remove the ANSI escape sequences used to print symbols in color
+// ----------------------------------------------------------------
+fn strip_color_codes(line: &str) -> String {
+  let mut stripped = String::with_capacity(line.len());
+  let mut chars = line.chars();
+  while let Some(c) = chars.next() {
+    if c == '\x1b' {
+      // Skip the sequence, up to its final letter (e.g. "\x1b[31m")
+      for c in chars.by_ref() {
+        if c.is_ascii_alphabetic() {
+          break;
+        }
+      }
+    } else {
+      stripped.push(c);
+    }
+  }
+  stripped
+}
@@ -0,0 +1,300 @@
+use std::io::Write;
+
+use colored::ColoredString;
+use ndarray::{Array2, ArrayView1};
+
+use crate::{Direction, Gate, MazeError, Openings, Pos, Theme};
+use crate::ascii::paint_symbol_row;
+use crate::theme::Glyph;
+
+
+// Box-drawing characters, indexed by the walls leaving a corner: 1 (up) + 2 (down) + 4 (left) + 8 (right)
+pub(crate) static JUNCTIONS: [&str; 16] = [" ", "╵", "╷", "│", "╴", "┘", "┐", "┤", "╶", "└", "┌", "├", "─", "┴", "┬", "┼"];
+
+
+// ----------------------------------------------------------------
+// Routine to display the maze with thin walls, drawn with box-drawing characters in a compact layout
+// Each row is printed on a single console line (plus a line for the top of the maze), in which the corners below...
+// ...the row alternate with its nodes: the corners are junctions computed from the walls that leave them (so the...
+// ...walls are drawn as continuous lines), whereas each node holds its symbol (or its floor, if it has none) in...
+// ...its own cell, followed by its floor: the side below it, which is thus never hidden by the symbol
+// ----------------------------------------------------------------
+pub fn write_maze_in_box_drawing(
+  out: &mut impl Write, maze: &Array2<u8>, entrance: &Gate, goal: &Gate, symbol_code: &Array2<u8>,
+  theme: &Theme) -> Result<(), MazeError> {
+  let mut symbs = Vec::with_capacity(symbol_code.nrows());
+  for (y, symbol_row) in symbol_code.rows().into_iter().enumerate() {
+    symbs.push(paint_symbol_row(symbol_row, y, theme)?);
+  }
+  write_painted_maze_in_box_drawing(out, maze, entrance, goal, symbs, theme)
+}
+
+
+// ----------------------------------------------------------------
+// Routine to display the maze with thin walls, each node being drawn with a symbol that has already been painted
+// ----------------------------------------------------------------
+pub(crate) fn write_painted_maze_in_box_drawing(
+  out: &mut impl Write, maze: &Array2<u8>, entrance: &Gate, goal: &Gate, symbs: Vec<Vec<ColoredString>>,
+  theme: &Theme) -> Result<(), MazeError> {
+
+  let height = maze.nrows();
+  for (y, symb_row) in symbs.into_iter().enumerate().take(height) {
+    let row_below = if y +1 < height {Some(maze.row(y +1))} else {None};
+    write_painted_row_in_box_drawing(out, maze.row(y), row_below, y, entrance, goal, symb_row, theme)?;
+  }
+  Ok(())
+}
+
+
+// ----------------------------------------------------------------
+// Routine to display the "y"-th row of the maze with thin walls (one console line per row, preceded by the top of...
+// ...the maze for the first row): the corners below the row also depend on the row below, if any
+// Rows can thus be printed one at a time, as soon as the next row is known, and the last row closes the maze
+// ----------------------------------------------------------------
+#[allow(clippy::too_many_arguments)]
+pub fn write_maze_row_in_box_drawing(
+  out: &mut impl Write, maze_row: ArrayView1<u8>, row_below: Option<ArrayView1<u8>>, y: usize, entrance: &Gate,
+  goal: &Gate, symbol_row: ArrayView1<u8>, theme: &Theme) -> Result<(), MazeError> {
+  let symbs = paint_symbol_row(symbol_row, y, theme)?;
+  write_painted_row_in_box_drawing(out, maze_row, row_below, y, entrance, goal, symbs, theme)
+}
+
+
+// ----------------------------------------------------------------
+// Routine to display the "y"-th row of the maze with thin walls, each node being drawn with an already-painted symbol
+// The entrance and the goal are drawn in their node, their outer wall (if any) being left open
+// The generic node symbol (as in the floorplan) is left out, so the floors of the nodes draw the maze
+// ----------------------------------------------------------------
+#[allow(clippy::too_many_arguments)]
+fn write_painted_row_in_box_drawing(
+  out: &mut impl Write, maze_row: ArrayView1<u8>, row_below: Option<ArrayView1<u8>>, y: usize, entrance: &Gate,
+  goal: &Gate, symbs: Vec<ColoredString>, theme: &Theme) -> Result<(), MazeError> {
+
+  let openings = get_row_openings(maze_row, y)?;
+  let openings_below = match row_below {
+    Some(row_below) => Some(get_row_openings(row_below, y +1)?),
+    None => None
+  };
+
+  // Top of the maze, above the first row
+  if y == 0 {
+    let top_line = get_corner_line(None, Some(&openings), 0, entrance, goal, theme, |_, is_wall| {
+      paint_side(is_wall, theme)
+    });
+    writeln!(out, "{}", top_line)?;
+  }
+
+  // Corners below the row, in-between which the nodes stand next to their floor
+  let line = get_corner_line(Some(&openings), openings_below.as_deref(), y +1, entrance, goal, theme, |x, is_wall| {
+    if entrance.pos == (Pos{x, y}) {
+      theme.entrance.paint()
+    } else if goal.pos == (Pos{x, y}) {
+      theme.goal.paint()
+    } else {
+      match symbs.get(x) {
+        Some(symb) if !symb.trim().is_empty() && **symb != theme.node.symbol => symb.clone(),
+        _ => paint_side(is_wall, theme)
+      }
+    }
+  });
+  writeln!(out, "{}", line)?;
+
+  // The last row closes the maze
+  if openings_below.is_none() {
+    writeln!(out)?;
+  }
+  Ok(())
+}
+
+
+// ----------------------------------------------------------------
+// Line of the corners above the "y"-th row, in-between the row above (if any) and the row below (if any)
+// In-between two corners, "paint_node" draws the cell of the "x"-th node, given whether the side under it is a wall...
+// ...and the side itself is drawn next to it
+// ----------------------------------------------------------------
+fn get_corner_line(
+  openings_above: Option<&[Openings]>, openings_below: Option<&[Openings]>, y: usize, entrance: &Gate, goal: &Gate,
+  theme: &Theme, mut paint_node: impl FnMut(usize, bool) -> ColoredString) -> String {
+
+  let width = openings_below.or(openings_above).map_or(0, |openings| openings.len());
+  let horizontal_walls: Vec<bool> = (0..width)
+    .map(|x| is_horizontal_wall(openings_below, x, y, entrance, goal))
+    .collect();
+
+  let mut line = String::new();
+  for x in 0..=width {
+    let is_wall_up = openings_above.is_some_and(|openings| is_vertical_wall(openings, x, y -1, entrance, goal));
+    let is_wall_down = openings_below.is_some_and(|openings| is_vertical_wall(openings, x, y, entrance, goal));
+    let is_wall_left = x > 0 && horizontal_walls[x -1];
+    let is_wall_right = x < width && horizontal_walls[x];
+    let junction = JUNCTIONS[
+      is_wall_up as usize + 2*(is_wall_down as usize) + 4*(is_wall_left as usize) + 8*(is_wall_right as usize)];
+    line.push_str(&theme.wall.paint_symbol(junction).to_string());
+    if let Some(&is_wall) = horizontal_walls.get(x) {
+      line.push_str(&paint_node(x, is_wall).to_string());
+      line.push_str(&paint_side(is_wall, theme).to_string());
+    }
+  }
+  line
+}
+
+
+// ----------------------------------------------------------------
+// Whether there is a wall on the left of the node ("x", "y"), or on the right of the last node of the row (if "x" is...
+// ...the width)
+// As in ascii-art, inner sides are given by the possible moves of the node on their right
+// ----------------------------------------------------------------
+fn is_vertical_wall(openings: &[Openings], x: usize, y: usize, entrance: &Gate, goal: &Gate) -> bool {
+  if x == 0 {
+    is_outer_wall(x, y, Direction::Left, entrance, goal)
+  } else if x == openings.len() {
+    is_outer_wall(x -1, y, Direction::Right, entrance, goal)
+  } else {
+    !openings[x].is_open(Direction::Left)
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Whether there is a wall on top of the node ("x", "y"), or at the bottom of the maze (if there is no row below)
+// As in ascii-art, inner sides are given by the possible moves of the node below them
+// ----------------------------------------------------------------
+fn is_horizontal_wall(openings_below: Option<&[Openings]>, x: usize, y: usize, entrance: &Gate, goal: &Gate) -> bool {
+  match openings_below {
+    None => is_outer_wall(x, y.saturating_sub(1), Direction::Down, entrance, goal),
+    Some(_) if y == 0 => is_outer_wall(x, y, Direction::Up, entrance, goal),
+    Some(openings) => !openings[x].is_open(Direction::Up)
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Whether the outer wall on a given side of the node ("x", "y") is closed, namely unless the entrance or the goal...
+// ...open on it
+// ----------------------------------------------------------------
+fn is_outer_wall(x: usize, y: usize, side: Direction, entrance: &Gate, goal: &Gate) -> bool {
+  !entrance.opens(x, y, side) && !goal.opens(x, y, side)
+}
+
+
+// ----------------------------------------------------------------
+// Symbol of a horizontal side: a wall (as a box-drawing line), or an opening
+// ----------------------------------------------------------------
+fn paint_side(is_wall: bool, theme: &Theme) -> ColoredString {
+  if is_wall {
+    theme.wall.paint_symbol("─")
+  } else {
+    theme.opening.paint()
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Possible moves from each node of the "y"-th row
+// The code "0" is only legit for a node that has not been connected yet, while the maze is being generated
+// ----------------------------------------------------------------
+fn get_row_openings(maze_row: ArrayView1<u8>, y: usize) -> Result<Vec<Openings>, MazeError> {
+  maze_row.iter().enumerate()
+    .map(|(x, &code)| Openings::from_bits(code).ok_or(MazeError::InvalidCode{pos: Pos{x, y}, code}))
+    .collect()
+}
+
+
+// ----------------------------------------------------------------
+// Read the possible moves from each node, along with the entrance and the goal, from the lines of a maze printed...
+// ...with thin walls: the walls are given by the junctions at the corners, and the entrance and the goal by the...
+// ...symbols in their node, along with the outer wall they open on (if any)
+// ----------------------------------------------------------------
+pub(crate) fn parse_maze_from_box_drawing(lines: &[Vec<char>]) -> Result<(Array2<u8>, Gate, Gate), MazeError> {
+
+  if lines.len() < 2 {
+    return Err(MazeError::Parse(
+      format!("{} line found, whereas a maze with thin walls is printed on at least two lines", lines.len())));
+  }
+  let nb_columns = lines[0].len();
+  if nb_columns < 4 || nb_columns % 3 != 1 {
+    return Err(MazeError::Parse(
+      format!("{} characters found per line, whereas a maze is printed on three per node plus one", nb_columns)));
+  }
+  if let Some(y) = lines.iter().position(|line| line.len() != nb_columns) {
+    return Err(MazeError::Parse(format!("line {} has {} characters instead of {}", y, lines[y].len(), nb_columns)));
+  }
+  let (width, height) = ((nb_columns -1)/3, lines.len() -1);
+
+  // Walls leaving each corner, each wall being shared by two corners that shall agree on it
+  let mut arms = Array2::<usize>::zeros((height +1, width +1));
+  for ((y, x), arm) in arms.indexed_iter_mut() {
+    let c = lines[y][3*x];
+    *arm = JUNCTIONS.iter().position(|junction| junction.starts_with(c)).ok_or_else(|| MazeError::Parse(
+      format!("unexpected `{}` at line {}, column {} (expected a corner)", c, y, 3*x)))?;
+  }
+  for ((y, x), &arm) in arms.indexed_iter() {
+    let arm_right = arms.get((y, x +1)).map_or(0, |arm_next| arm_next & 4);
+    let arm_below = arms.get((y +1, x)).map_or(0, |arm_next| arm_next & 1);
+    let is_broken = (arm & 8 != 0) != (arm_right != 0) || (arm & 2 != 0) != (arm_below != 0) ||
+      (x == 0 && arm & 4 != 0) || (y == 0 && arm & 1 != 0);
+    if is_broken {
+      return Err(MazeError::Parse(format!("broken wall at line {}, column {}", y, 3*x)));
+    }
+  }
+
+  // Sides of the node ("x", "y") are given by the corner on its top-left (left and top sides), by the corner on its...
+  // ...top-right (right side), and by the corner on its bottom-left (bottom side)
+  let is_wall = |pos: &Pos, side: Direction| match side {
+    Direction::Left => arms[[pos.y, pos.x]] & 2 != 0,
+    Direction::Right => arms[[pos.y, pos.x +1]] & 2 != 0,
+    Direction::Up => arms[[pos.y, pos.x]] & 8 != 0,
+    Direction::Down => arms[[pos.y +1, pos.x]] & 8 != 0
+  };
+  let is_outer = |pos: &Pos, side: Direction| side.step(pos).is_none_or(|next| next.x >= width || next.y >= height);
+  let nodes: Vec<Pos> = (0..height).flat_map(|y| (0..width).map(move |x| Pos{x, y})).collect();
+
+  let mut codes = Array2::<u8>::zeros((height, width));
+  for pos in nodes.iter() {
+    let mut openings = Openings::empty();
+    for side in Direction::ALL {
+      if !is_outer(pos, side) && !is_wall(pos, side) {
+        openings.open(side);
+      }
+    }
+    codes[[pos.y, pos.x]] = openings.bits();
+  }
+
+  // The entrance and the goal are drawn in their node, with the symbols of a theme with thin walls
+  let themes: Vec<Theme> = Theme::builtins().into_iter()
+    .map(|(_, theme)| theme)
+    .filter(|theme| theme.thin_walls)
+    .collect();
+  let find_gate = |role: fn(&Theme) -> &Glyph, name: &str| -> Result<Gate, MazeError> {
+    let mut gates = nodes.iter()
+      .filter(|pos| themes.iter().any(|theme| role(theme).symbol.starts_with(lines[pos.y +1][3*pos.x +1])));
+    let pos = match (gates.next(), gates.next()) {
+      (Some(pos), None) => *pos,
+      (None, _) => return Err(MazeError::Parse(format!("the {} is missing", name))),
+      (Some(_), Some(_)) => return Err(MazeError::Parse(format!("the {} is drawn twice", name)))
+    };
+    let sides: Vec<Direction> = Direction::ALL.into_iter()
+      .filter(|&side| is_outer(&pos, side) && !is_wall(&pos, side))
+      .collect();
+    match sides[..] {
+      [] => Ok(Gate{pos, side: None}),
+      [side] => Ok(Gate{pos, side: Some(side)}),
+      _ => Err(MazeError::Parse(format!("the {} opens on several sides", name)))
+    }
+  };
+  let entrance = find_gate(|theme| &theme.entrance, "entrance")?;
+  let goal = find_gate(|theme| &theme.goal, "goal")?;
+
+  // The outer walls are closed, except for the entrance and the goal, when they open on them
+  for pos in nodes.iter() {
+    for side in Direction::ALL {
+      if is_outer(pos, side) && !is_wall(pos, side) && !entrance.opens(pos.x, pos.y, side) &&
+        !goal.opens(pos.x, pos.y, side) {
+        return Err(MazeError::Parse(
+          format!("the outer walls are open next to the node ({},{}), which is neither the entrance nor the goal",
+            pos.x, pos.y)));
+      }
+    }
+  }
+  Ok((codes, entrance, goal))
+}
@@ -0,0 +1,76 @@
+use ndarray::Array2;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::{Direction, Openings, Pos};
+use crate::generation::{connect, get_neighbors};
+
+
+// ----------------------------------------------------------------
+// Remove a fraction (between 0 and 1) of the dead ends of the maze by opening an extra door from each of them...
+// ...preferably towards a neighbor that is itself a dead end, so two dead ends are removed with a single door
+// The maze is then no longer perfect: it contains loops, and the path from the entrance to the goal may not be unique
+// Return the number of doors that have been opened
+// ----------------------------------------------------------------
+pub fn braid_maze(maze: &mut Array2<u8>, fraction: f64, rng: &mut StdRng) -> usize {
+
+  let (height, width) = maze.dim();
+
+  // List the dead ends, in random order
+  let mut dead_ends = vec![];
+  for y in 0..height {
+    for x in 0..width {
+      if is_dead_end(maze[[y, x]]) {
+        dead_ends.push(Pos{x, y});
+      }
+    }
+  }
+  dead_ends.shuffle(rng);
+  let nb_dead_ends_to_remove = (fraction.clamp(0.0, 1.0) * dead_ends.len() as f64).round() as usize;
+
+  let mut nb_doors = 0;
+  for pos in dead_ends.iter().take(nb_dead_ends_to_remove) {
+
+    // The dead end may already have been removed, when a door was opened from one of its neighbors
+    if !is_dead_end(maze[[pos.y, pos.x]]) {
+      continue;
+    }
+
+    // Candidate neighbors are those that are not connected yet (namely, all but the one the dead end leads to)
+    let candidates: Vec<Pos> = get_neighbors(width, height, pos).into_iter()
+      .filter(|neighbor| !are_connected(maze, pos, neighbor))
+      .collect();
+    let dead_end_candidates: Vec<Pos> = candidates.iter()
+      .filter(|neighbor| is_dead_end(maze[[neighbor.y, neighbor.x]]))
+      .copied()
+      .collect();
+    let pos_neighbor = if !dead_end_candidates.is_empty() {
+      dead_end_candidates.choose(rng)
+    } else {
+      candidates.choose(rng)
+    };
+
+    if let Some(pos_neighbor) = pos_neighbor {
+      connect(maze, pos, pos_neighbor);
+      nb_doors += 1;
+    }
+  }
+  nb_doors
+}
+
+
+// ----------------------------------------------------------------
+// A dead end is a node with a single possible move, namely, a code-word among {L2R, R2L, U2D, D2U}
+// ----------------------------------------------------------------
+fn is_dead_end(code: u8) -> bool {
+  Openings::from_bits(code).is_some_and(|openings| openings.len() == 1)
+}
+
+
+// ----------------------------------------------------------------
+// Check whether there is a door between two neighbor nodes
+// ----------------------------------------------------------------
+fn are_connected(maze: &Array2<u8>, pos_a: &Pos, pos_b: &Pos) -> bool {
+  let openings = Openings::from_bits_truncate(maze[[pos_a.y, pos_a.x]]);
+  Direction::between(pos_a, pos_b).is_some_and(|dir| openings.is_open(dir))
+}
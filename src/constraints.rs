@@ -0,0 +1,227 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rand::rngs::StdRng;
+
+use crate::{Maze, MazeError, MazeGenerator, MazeMetrics, Placement};
+use crate::distance::get_shortest_path;
+use crate::metrics::compute_metrics;
+
+
+// Figure of "MazeMetrics" a constraint applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+  DeadEnds,       // number of dead ends
+  Junctions,      // number of junctions
+  Corridors,      // number of corridors
+  SolutionLength, // number of nodes along the shortest path
+  SolutionRatio,  // fraction of the nodes that lie on the shortest path
+  Turns,          // changes of direction along the shortest path
+  RiverFactor,    // fraction of the nodes with exactly two possible moves
+  BranchingFactor // average number of ways forward at each node of the shortest path
+}
+
+impl Metric {
+
+  pub const ALL: [Metric; 8] = [
+    Metric::DeadEnds, Metric::Junctions, Metric::Corridors, Metric::SolutionLength, Metric::SolutionRatio,
+    Metric::Turns, Metric::RiverFactor, Metric::BranchingFactor];
+
+
+  // Name of the metric, as written in a constraint (e.g. "dead-ends>=20")
+  pub fn name(self) -> &'static str {
+    match self {
+      Metric::DeadEnds => "dead-ends",
+      Metric::Junctions => "junctions",
+      Metric::Corridors => "corridors",
+      Metric::SolutionLength => "solution-length",
+      Metric::SolutionRatio => "solution-ratio",
+      Metric::Turns => "turns",
+      Metric::RiverFactor => "river-factor",
+      Metric::BranchingFactor => "branching-factor"
+    }
+  }
+
+
+  // Value of the metric for a given maze
+  pub fn value(self, metrics: &MazeMetrics) -> f64 {
+    match self {
+      Metric::DeadEnds => metrics.nb_dead_ends as f64,
+      Metric::Junctions => metrics.nb_junctions as f64,
+      Metric::Corridors => metrics.nb_corridors() as f64,
+      Metric::SolutionLength => metrics.solution_length as f64,
+      Metric::SolutionRatio => metrics.solution_ratio,
+      Metric::Turns => metrics.nb_turns as f64,
+      Metric::RiverFactor => metrics.river_factor,
+      Metric::BranchingFactor => metrics.branching_factor
+    }
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Range of values that a metric of the maze shall lie in (both bounds included, if any)
+// A constraint is written as "metric>=min", "metric<=max", "metric=min..max", or "metric=value", e.g....
+// ..."solution-ratio>=0.6" (solution through at least 60% of the nodes), "dead-ends>=20", or "turns=30..50"
+// ----------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Constraint {
+  pub metric: Metric,
+  pub min: Option<f64>,
+  pub max: Option<f64>
+}
+
+impl Constraint {
+
+  pub fn at_least(metric: Metric, min: f64) -> Constraint {
+    Constraint{metric, min: Some(min), max: None}
+  }
+
+  pub fn at_most(metric: Metric, max: f64) -> Constraint {
+    Constraint{metric, min: None, max: Some(max)}
+  }
+
+  pub fn between(metric: Metric, min: f64, max: f64) -> Constraint {
+    Constraint{metric, min: Some(min), max: Some(max)}
+  }
+
+
+  // ----------------------------------------------------------------
+  // How far a maze is from meeting the constraint: 0 if it is met, otherwise the distance of the metric to the...
+  // ...violated bound, relative to this bound (or absolute, for bounds below 1, e.g. the fractions)
+  // ----------------------------------------------------------------
+  pub fn gap(&self, metrics: &MazeMetrics) -> f64 {
+    let value = self.metric.value(metrics);
+    let relative_gap = |bound: f64| (value - bound).abs()/bound.abs().max(1.0);
+    match (self.min, self.max) {
+      (Some(min), _) if value < min => relative_gap(min),
+      (_, Some(max)) if value > max => relative_gap(max),
+      _ => 0.0
+    }
+  }
+
+
+  pub fn is_met(&self, metrics: &MazeMetrics) -> bool {
+    self.gap(metrics) == 0.0
+  }
+}
+
+impl fmt::Display for Constraint {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match (self.min, self.max) {
+      (Some(min), Some(max)) if min == max => write!(f, "{}={}", self.metric.name(), min),
+      (Some(min), Some(max)) => write!(f, "{}={}..{}", self.metric.name(), min, max),
+      (Some(min), None) => write!(f, "{}>={}", self.metric.name(), min),
+      (None, Some(max)) => write!(f, "{}<={}", self.metric.name(), max),
+      (None, None) => write!(f, "{} (any)", self.metric.name())
+    }
+  }
+}
+
+impl FromStr for Constraint {
+  type Err = MazeError;
+
+  fn from_str(text: &str) -> Result<Constraint, MazeError> {
+    let error = |message: String| MazeError::Parse(format!("invalid constraint `{}`: {}", text, message));
+    let parse_value = |value: &str| value.trim().parse::<f64>()
+      .ok()
+      .filter(|value| value.is_finite())
+      .ok_or_else(|| error(format!("`{}` is not a number", value.trim())));
+
+    let (name, operator, bound) = [">=", "<=", "="].iter()
+      .find_map(|operator| text.split_once(operator).map(|(name, bound)| (name.trim(), *operator, bound)))
+      .ok_or_else(|| error(String::from("expected metric>=min, metric<=max, metric=min..max, or metric=value")))?;
+    let metric = Metric::ALL.into_iter().find(|metric| metric.name() == name).ok_or_else(|| {
+      let names: Vec<&str> = Metric::ALL.iter().map(|metric| metric.name()).collect();
+      error(format!("unknown metric `{}` (expected {})", name, names.join(", ")))
+    })?;
+    match (operator, bound.split_once("..")) {
+      (">=", _) => Ok(Constraint::at_least(metric, parse_value(bound)?)),
+      ("<=", _) => Ok(Constraint::at_most(metric, parse_value(bound)?)),
+      (_, Some((min, max))) => {
+        let (min, max) = (parse_value(min)?, parse_value(max)?);
+        if min > max {
+          return Err(error(format!("the range {}..{} is empty", min, max)));
+        }
+        Ok(Constraint::between(metric, min, max))
+      },
+      (_, None) => {
+        let value = parse_value(bound)?;
+        Ok(Constraint::between(metric, value, value))
+      }
+    }
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Outcome of the generation of a maze under constraints: the figures of the returned maze, and how close it got
+// ----------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstraintReport {
+  pub constraints: Vec<Constraint>, // requested constraints
+  pub metrics: MazeMetrics,         // figures of the returned maze
+  pub nb_attempts: usize,           // number of mazes generated
+  pub gap: f64                      // sum of the gaps to each constraint (0 if they are all met, see "Constraint::gap")
+}
+
+impl ConstraintReport {
+
+  pub fn is_met(&self) -> bool {
+    self.gap == 0.0
+  }
+}
+
+impl fmt::Display for ConstraintReport {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    for constraint in self.constraints.iter() {
+      let value = constraint.metric.value(&self.metrics);
+      let status = if constraint.is_met(&self.metrics) {"met"} else {"NOT met"};
+      writeln!(f, "| {:<24} | {:>8.2} | {:<7} |", constraint.to_string(), value, status)?;
+    }
+    Ok(())
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Generate mazes until they meet all the constraints, or until "max_attempts" mazes have been generated
+// The maze that came the closest to the constraints (namely, with the smallest sum of gaps) is returned, along with...
+// ...a report telling whether the constraints are met
+// Each attempt is a brand new maze (entrance and goal included, placed with "placement" if any), measured along...
+// ...its shortest path
+// ----------------------------------------------------------------
+#[allow(clippy::too_many_arguments)]
+pub fn generate_with_constraints(
+  width: usize, height: usize, orientation: bool, generator: &dyn MazeGenerator, placement: Option<&Placement>,
+  constraints: &[Constraint], max_attempts: usize, rng: &mut StdRng) -> Result<(Maze, ConstraintReport), MazeError> {
+
+  let generate_and_measure = |rng: &mut StdRng| -> Result<(Maze, MazeMetrics, f64), MazeError> {
+    let mut maze = Maze::generate(width, height, orientation, generator, None, rng)?;
+    if let Some(placement) = placement {
+      maze.place(placement, rng)?;
+    }
+    measure(maze, constraints)
+  };
+  let (mut maze, mut metrics, mut gap) = generate_and_measure(rng)?;
+  let mut nb_attempts = 1;
+  while gap > 0.0 && nb_attempts < max_attempts {
+    let attempt = generate_and_measure(rng)?;
+    nb_attempts += 1;
+    if attempt.2 < gap {
+      (maze, metrics, gap) = attempt;
+    }
+  }
+  Ok((maze, ConstraintReport{constraints: constraints.to_vec(), metrics, nb_attempts, gap}))
+}
+
+
+// ----------------------------------------------------------------
+// Measure a maze, and how far it is from meeting the constraints (sum of the gaps)
+// ----------------------------------------------------------------
+fn measure(maze: Maze, constraints: &[Constraint]) -> Result<(Maze, MazeMetrics, f64), MazeError> {
+  let path = get_shortest_path(&maze.codes, &maze.pos_entrance, &maze.pos_goal).ok_or(MazeError::UnreachableGoal)?;
+  let metrics = compute_metrics(&maze, &path)?;
+  let gap = constraints.iter().map(|constraint| constraint.gap(&metrics)).sum();
+  Ok((maze, metrics, gap))
+}
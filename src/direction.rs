@@ -0,0 +1,152 @@
+use std::fmt;
+
+use crate::{Pos, L2R, R2L, U2D, D2U};
+
+
+// Direction of a move from a node to one of its neighbors, each direction matching one of the code-words
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+  Left,  // "right to left" move (R2L)
+  Right, // "left to right" move (L2R)
+  Up,    // "down to up" move (D2U)
+  Down   // "up to down" move (U2D)
+}
+
+impl Direction {
+
+  // All the directions, in the order the possible moves from a node are explored
+  pub const ALL: [Direction; 4] = [Direction::Left, Direction::Right, Direction::Up, Direction::Down];
+
+
+  // Code-word of the move
+  pub fn code(self) -> u8 {
+    match self {
+      Direction::Left => R2L,
+      Direction::Right => L2R,
+      Direction::Up => D2U,
+      Direction::Down => U2D
+    }
+  }
+
+
+  // Direction of the move back
+  pub fn opposite(self) -> Direction {
+    match self {
+      Direction::Left => Direction::Right,
+      Direction::Right => Direction::Left,
+      Direction::Up => Direction::Down,
+      Direction::Down => Direction::Up
+    }
+  }
+
+
+  // Neighbor reached from a given node, if any (the right and bottom sides of the maze are left to the caller)
+  pub fn step(self, pos: &Pos) -> Option<Pos> {
+    match self {
+      Direction::Left => pos.x.checked_sub(1).map(|x| Pos{x, y: pos.y}),
+      Direction::Right => Some(Pos{x: pos.x +1, y: pos.y}),
+      Direction::Up => pos.y.checked_sub(1).map(|y| Pos{x: pos.x, y}),
+      Direction::Down => Some(Pos{x: pos.x, y: pos.y +1})
+    }
+  }
+
+
+  // Direction of the move in-between two neighbor nodes, if they are indeed neighbors
+  pub fn between(pos_a: &Pos, pos_b: &Pos) -> Option<Direction> {
+    Direction::ALL.into_iter().find(|dir| dir.step(pos_a) == Some(*pos_b))
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Set of the possible moves from a node, stored as the sum of their code-words (namely, the hexadecimal code 0 to F)
+// The hexadecimal code remains the serialized form: see "Openings::bits" and "Openings::from_bits"
+// ----------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Openings(u8);
+
+impl Openings {
+
+  // Fully-closed node
+  pub const fn empty() -> Openings {
+    Openings(0)
+  }
+
+
+  // Node open in all four directions
+  pub fn all() -> Openings {
+    Openings(L2R + R2L + U2D + D2U)
+  }
+
+
+  // Read the hexadecimal code of a node, rejecting codes above F
+  pub fn from_bits(code: u8) -> Option<Openings> {
+    if code <= Openings::all().0 {
+      Some(Openings(code))
+    } else {
+      None
+    }
+  }
+
+
+  // Read the hexadecimal code of a node, ignoring anything above F
+  pub fn from_bits_truncate(code: u8) -> Openings {
+    Openings(code & Openings::all().0)
+  }
+
+
+  // Hexadecimal code of the node
+  pub fn bits(self) -> u8 {
+    self.0
+  }
+
+
+  pub fn is_empty(self) -> bool {
+    self.0 == 0
+  }
+
+
+  // Number of possible moves
+  pub fn len(self) -> usize {
+    self.0.count_ones() as usize
+  }
+
+
+  pub fn is_open(self, dir: Direction) -> bool {
+    self.0 & dir.code() != 0
+  }
+
+
+  pub fn open(&mut self, dir: Direction) {
+    self.0 |= dir.code();
+  }
+
+
+  pub fn close(&mut self, dir: Direction) {
+    self.0 &= !dir.code();
+  }
+
+
+  // Directions of the possible moves, in the order of "Direction::ALL"
+  pub fn iter(self) -> impl Iterator<Item = Direction> {
+    Direction::ALL.into_iter().filter(move |&dir| self.is_open(dir))
+  }
+
+
+  // Nodes reached via the possible moves from a given node (moves through the left and top sides being ignored)
+  pub fn neighbors(self, pos: &Pos) -> Vec<Pos> {
+    self.iter().filter_map(|dir| dir.step(pos)).collect()
+  }
+}
+
+impl From<Direction> for Openings {
+  fn from(dir: Direction) -> Openings {
+    Openings(dir.code())
+  }
+}
+
+impl fmt::Display for Openings {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:X}", self.0)
+  }
+}
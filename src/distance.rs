@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+use ndarray::Array2;
+
+use crate::{Openings, Pos};
+use crate::raster::Rgb;
+
+
+// Colors of the heat map, from the nearest nodes (blue) to the farthest ones (red), evenly spread along the distance
+static GRADIENT: [Rgb; 5] = [[44, 123, 182], [171, 217, 233], [255, 255, 191], [253, 174, 97], [215, 25, 28]];
+
+
+// ----------------------------------------------------------------
+// Flood-fill the maze from a given node, and return the number of moves needed to reach each node from it...
+// ...or "None" for the nodes that cannot be reached (e.g. in a maze that is being generated)
+// In a perfect maze, this is the length of the unique path in-between the two nodes
+// ----------------------------------------------------------------
+pub fn get_distance_map(maze: &Array2<u8>, pos_origin: &Pos) -> Array2<Option<usize>> {
+
+  let (height, width) = maze.dim();
+  let mut distances = Array2::<Option<usize>>::from_elem((height, width), None);
+  if pos_origin.x >= width || pos_origin.y >= height {
+    return distances;
+  }
+
+  // Breadth-first traversal, so each node is reached via one of its shortest paths
+  distances[[pos_origin.y, pos_origin.x]] = Some(0);
+  let mut queue = VecDeque::from([(*pos_origin, 0)]);
+  while let Some((pos, distance)) = queue.pop_front() {
+    for pos_next in Openings::from_bits_truncate(maze[[pos.y, pos.x]]).neighbors(&pos) {
+      if pos_next.x < width && pos_next.y < height && distances[[pos_next.y, pos_next.x]].is_none() {
+        distances[[pos_next.y, pos_next.x]] = Some(distance +1);
+        queue.push_back((pos_next, distance +1));
+      }
+    }
+  }
+  distances
+}
+
+
+// ----------------------------------------------------------------
+// Shortest path in-between two nodes (both included), if any, found by walking back from the target towards the...
+// ...origin along decreasing distances
+// ----------------------------------------------------------------
+pub fn get_shortest_path(maze: &Array2<u8>, pos_origin: &Pos, pos_target: &Pos) -> Option<Vec<Pos>> {
+  let distances = get_distance_map(maze, pos_origin);
+  let mut distance = (*distances.get((pos_target.y, pos_target.x))?)?;
+  let mut path = vec![*pos_target];
+  while distance > 0 {
+    let pos = path[path.len() -1];
+    let pos_prev = Openings::from_bits_truncate(maze[[pos.y, pos.x]]).neighbors(&pos).into_iter()
+      .find(|pos_prev| distances.get((pos_prev.y, pos_prev.x)) == Some(&Some(distance -1)))?;
+    path.push(pos_prev);
+    distance -= 1;
+  }
+  path.reverse();
+  Some(path)
+}
+
+
+// ----------------------------------------------------------------
+// Largest distance of the map (0 if no node can be reached)
+// ----------------------------------------------------------------
+pub fn get_max_distance(distances: &Array2<Option<usize>>) -> usize {
+  distances.iter().flatten().copied().max().unwrap_or(0)
+}
+
+
+// ----------------------------------------------------------------
+// Color of a distance on the gradient of the heat map, linearly interpolated in-between its two nearest colors
+// ----------------------------------------------------------------
+pub fn get_gradient_color(distance: usize, max_distance: usize) -> Rgb {
+  if max_distance == 0 {
+    return GRADIENT[0];
+  }
+  let position = (GRADIENT.len() -1) as f64*distance.min(max_distance) as f64/max_distance as f64;
+  let idx = (position.floor() as usize).min(GRADIENT.len() -2);
+  let weight = position - idx as f64;
+  let (color_a, color_b) = (GRADIENT[idx], GRADIENT[idx +1]);
+  [0, 1, 2].map(|channel| {
+    (color_a[channel] as f64*(1.0 - weight) + color_b[channel] as f64*weight).round() as u8
+  })
+}
+
+
+// ----------------------------------------------------------------
+// Tenth of the largest distance reached by a node (from 0 to 9), printed in the heat map so it remains readable...
+// ...without colors
+// ----------------------------------------------------------------
+pub fn get_distance_decile(distance: usize, max_distance: usize) -> usize {
+  if max_distance == 0 {
+    0
+  } else {
+    10*distance.min(max_distance)/(max_distance +1)
+  }
+}
@@ -0,0 +1,79 @@
+use std::{fmt, io};
+
+use crate::{Pos, ValidationError};
+
+
+// Error returned by the library instead of aborting the process, e.g. on a maze built or loaded from a bad input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MazeError {
+  TooSmall{width: usize, height: usize},   // a maze needs at least two nodes
+  Parse(String),                           // unreadable maze (JSON, hexadecimal format, ascii-art) or constraint
+  Inconsistent(Vec<ValidationError>),      // inconsistent move codes, or misplaced entrance/goal
+  InvalidCode{pos: Pos, code: u8},         // code-word with no possible move, met while solving or rendering
+  InvalidSymbol{pos: Pos, symbol_code: u8}, // codename with no corresponding ascii symbol
+  InvalidRegions{pos: Pos},                // node in none of the alive/narrow/far regions
+  InvalidPath{pos_a: Pos, pos_b: Pos},     // subsequent nodes of a path that are not neighbors
+  UnreachableGoal,                         // front propagation exhausted before reaching the goal
+  OutOfBounds{pos: Pos},                   // node outside of the maze, e.g. the origin of a distance map
+  InvalidPlacement(String),                // entrance and goal that cannot be placed with the requested strategy
+  ImageTooLarge{width: usize, height: usize, max_size: usize}, // image beyond the limits of its format (e.g. GIF)
+  Io(io::ErrorKind, String)                // output that could not be written (e.g. a closed pipe), and why
+}
+
+impl fmt::Display for MazeError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      MazeError::TooSmall{width, height} =>
+        write!(f, "a {}x{} maze is too small (at least two nodes are needed)", width, height),
+      MazeError::Parse(message) =>
+        write!(f, "{}", message),
+      MazeError::Inconsistent(errors) =>
+        write!(f, "{}", errors.iter().map(|error| error.to_string()).collect::<Vec<String>>().join("; ")),
+      MazeError::InvalidCode{pos, code} =>
+        write!(f, "invalid code {} at ({},{})", code, pos.x, pos.y),
+      MazeError::InvalidSymbol{pos, symbol_code} =>
+        write!(f, "no ascii symbol for the codename {} at ({},{})", symbol_code, pos.x, pos.y),
+      MazeError::InvalidRegions{pos} =>
+        write!(f, "the node ({},{}) is in none of the alive/narrow/far regions", pos.x, pos.y),
+      MazeError::InvalidPath{pos_a, pos_b} =>
+        write!(
+          f, "the path jumps from ({},{}) to ({},{}), which are not neighbors", pos_a.x, pos_a.y, pos_b.x, pos_b.y),
+      MazeError::UnreachableGoal =>
+        write!(f, "the goal cannot be reached from the entrance"),
+      MazeError::OutOfBounds{pos} =>
+        write!(f, "the node ({},{}) is outside of the maze", pos.x, pos.y),
+      MazeError::InvalidPlacement(message) =>
+        write!(f, "{}", message),
+      MazeError::ImageTooLarge{width, height, max_size} =>
+        write!(f, "a {}x{} image is too large (at most {} pixels along each side)", width, height, max_size),
+      MazeError::Io(_, message) =>
+        write!(f, "cannot write the maze: {}", message)
+    }
+  }
+}
+
+impl std::error::Error for MazeError {}
+
+impl From<io::Error> for MazeError {
+  fn from(error: io::Error) -> MazeError {
+    MazeError::Io(error.kind(), error.to_string())
+  }
+}
+
+impl From<png::EncodingError> for MazeError {
+  fn from(error: png::EncodingError) -> MazeError {
+    match error {
+      png::EncodingError::IoError(error) => MazeError::from(error),
+      error => MazeError::Io(io::ErrorKind::InvalidData, error.to_string())
+    }
+  }
+}
+
+impl From<gif::EncodingError> for MazeError {
+  fn from(error: gif::EncodingError) -> MazeError {
+    match error {
+      gif::EncodingError::Io(error) => MazeError::from(error),
+      error => MazeError::Io(io::ErrorKind::InvalidData, error.to_string())
+    }
+  }
+}
@@ -0,0 +1,57 @@
+use ndarray::Array2;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::{MazeError, Pos};
+use super::{check_size, connect, get_neighbors, MazeGenerator, ShowGeneration};
+
+
+// Aldous-Broder algorithm: a single random walk, yielding an unbiased sample among all the possible perfect mazes
+pub struct AldousBroder;
+
+impl MazeGenerator for AldousBroder {
+
+  fn name(&self) -> &'static str {
+    "aldous-broder"
+  }
+
+  // ----------------------------------------------------------------
+  // Randomly walk across the maze, and connect each node to the previous node of the walk when first visited
+  // The walk stops once every node has been visited (which may take a while for large mazes)
+  // ----------------------------------------------------------------
+  fn generate_maze(
+    &self, width: usize, height: usize, _orientation: bool, mut show_generation: Option<ShowGeneration>,
+    rng: &mut StdRng) -> Result<(Array2<u8>, usize), MazeError> {
+
+    check_size(width, height)?;
+
+    let mut maze = Array2::<u8>::zeros((height, width));
+    let mut visited = Array2::<bool>::from_elem((height, width), false);
+    let mut nb_iter_create: usize = 0;
+
+    // Start the walk at a random node
+    let mut pos = Pos{x: rng.gen_range(0..width), y: rng.gen_range(0..height)};
+    visited[[pos.y, pos.x]] = true;
+    let mut nb_unvisited = width*height -1;
+
+    while nb_unvisited > 0 {
+
+      // Increment the number of iterations
+      nb_iter_create += 1;
+
+      let next = *get_neighbors(width, height, &pos).choose(rng).unwrap();
+      if !visited[[next.y, next.x]] {
+        connect(&mut maze, &pos, &next);
+        visited[[next.y, next.x]] = true;
+        nb_unvisited -= 1;
+        if let Some(show_generation) = show_generation.as_mut() {
+          show_generation(nb_iter_create, &maze);
+        }
+      }
+      pos = next;
+    }
+
+    Ok((maze, nb_iter_create))
+  }
+}
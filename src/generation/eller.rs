@@ -0,0 +1,132 @@
+use ndarray::{Array2, ArrayView1};
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::{L2R, R2L, U2D, D2U, MazeError};
+use super::{check_size, MazeGenerator, ShowGeneration};
+
+
+// Eller's algorithm: the maze is generated one row at a time, with a memory proportional to the width only
+pub struct Eller;
+
+impl Eller {
+
+  // ----------------------------------------------------------------
+  // Iterate over the rows of a maze of the given width, each row holding the possible moves from each of its nodes
+  // The maze is unbounded (namely, the iterator never ends) if no height is given
+  // ----------------------------------------------------------------
+  pub fn rows(width: usize, height: Option<usize>, rng: &mut StdRng) -> EllerRows<'_> {
+    EllerRows{width, height, y: 0, sets: vec![0; width], connected_up: vec![false; width], nb_sets: 0, rng}
+  }
+}
+
+impl MazeGenerator for Eller {
+
+  fn name(&self) -> &'static str {
+    "eller"
+  }
+
+  fn generate_maze(
+    &self, width: usize, height: usize, _orientation: bool, mut show_generation: Option<ShowGeneration>,
+    rng: &mut StdRng) -> Result<(Array2<u8>, usize), MazeError> {
+
+    check_size(width, height)?;
+
+    let mut maze = Array2::<u8>::zeros((height, width));
+    let mut nb_iter_create: usize = 0;
+    for (y, row) in Eller::rows(width, Some(height), rng).enumerate() {
+      nb_iter_create += 1;
+      maze.row_mut(y).assign(&ArrayView1::from(&row[..]));
+      if let Some(show_generation) = show_generation.as_mut() {
+        show_generation(nb_iter_create, &maze);
+      }
+    }
+
+    Ok((maze, nb_iter_create))
+  }
+}
+
+
+// ----------------------------------------------------------------
+// State of Eller's algorithm in-between two rows: the set of connected nodes each node of the current row belongs to...
+// ...and whether it is connected to the row above
+// ----------------------------------------------------------------
+pub struct EllerRows<'a> {
+  width: usize,
+  height: Option<usize>,
+  y: usize,
+  sets: Vec<usize>,
+  connected_up: Vec<bool>,
+  nb_sets: usize,
+  rng: &'a mut StdRng
+}
+
+impl Iterator for EllerRows<'_> {
+  type Item = Vec<u8>;
+
+  // ----------------------------------------------------------------
+  // 0. Nodes that are not connected to the row above are placed in a new set of their own
+  // 1. Randomly connect neighbor nodes of the row that belong to different sets (and merge their sets)
+  // 2. Randomly connect nodes to the row below, at least once per set, so no set is left behind
+  // On the last row, all the neighbor nodes that belong to different sets are connected, and nothing goes down
+  // ----------------------------------------------------------------
+  fn next(&mut self) -> Option<Vec<u8>> {
+
+    if self.height.is_some_and(|height| self.y >= height) || self.width == 0 {
+      return None;
+    }
+    let is_last_row = self.height.is_some_and(|height| self.y +1 == height);
+    let mut row = vec![0; self.width];
+
+    // Step 0: new sets for the nodes that are not connected to the row above
+    for (x, code) in row.iter_mut().enumerate() {
+      if self.connected_up[x] {
+        *code += D2U;
+      } else {
+        self.sets[x] = self.nb_sets;
+        self.nb_sets += 1;
+      }
+    }
+
+    // Step 1: horizontal connections
+    for x in 0..self.width -1 {
+      if self.sets[x] != self.sets[x +1] && (is_last_row || self.rng.gen_bool(0.5)) {
+        row[x] += L2R;
+        row[x +1] += R2L;
+        let (set_kept, set_merged) = (self.sets[x], self.sets[x +1]);
+        for set in self.sets.iter_mut() {
+          if *set == set_merged {
+            *set = set_kept;
+          }
+        }
+      }
+    }
+
+    // Step 2: vertical connections, visiting the nodes set by set (in a deterministic order, for reproducibility)
+    self.connected_up = vec![false; self.width];
+    if !is_last_row {
+      let mut idx_sorted: Vec<usize> = (0..self.width).collect();
+      idx_sorted.sort_by_key(|&x| self.sets[x]);
+      let mut idx_start = 0;
+      while idx_start < self.width {
+        let mut idx_end = idx_start;
+        while idx_end < self.width && self.sets[idx_sorted[idx_end]] == self.sets[idx_sorted[idx_start]] {
+          idx_end += 1;
+        }
+        // One mandatory connection per set, plus random ones
+        let x_mandatory = idx_sorted[self.rng.gen_range(idx_start..idx_end)];
+        for &x in idx_sorted[idx_start..idx_end].iter() {
+          if x == x_mandatory || self.rng.gen_bool(0.5) {
+            row[x] += U2D;
+            self.connected_up[x] = true;
+          }
+        }
+        idx_start = idx_end;
+      }
+    }
+
+    self.y += 1;
+    Some(row)
+  }
+}
+
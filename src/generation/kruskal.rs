@@ -0,0 +1,84 @@
+use ndarray::Array2;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::{MazeError, Pos};
+use super::{check_size, connect, MazeGenerator, ShowGeneration};
+
+
+// Randomized Kruskal's algorithm: short dead ends, evenly spread over the whole maze
+pub struct Kruskal;
+
+impl MazeGenerator for Kruskal {
+
+  fn name(&self) -> &'static str {
+    "kruskal"
+  }
+
+  // ----------------------------------------------------------------
+  // Visit all the pairs of neighbor nodes in random order, and connect the two nodes of a pair...
+  // ...whenever they do not belong to the same set of connected nodes yet (the two sets are then merged)
+  // ----------------------------------------------------------------
+  fn generate_maze(
+    &self, width: usize, height: usize, _orientation: bool, mut show_generation: Option<ShowGeneration>,
+    rng: &mut StdRng) -> Result<(Array2<u8>, usize), MazeError> {
+
+    check_size(width, height)?;
+
+    let mut maze = Array2::<u8>::zeros((height, width));
+    let mut nb_iter_create: usize = 0;
+
+    // Each node initially forms its own set, identified by the index "y*width + x"
+    let mut sets: Vec<usize> = (0..width*height).collect();
+
+    // List all the pairs of neighbor nodes, in random order
+    let mut pairs = vec![];
+    for y in 0..height {
+      for x in 0..width {
+        if x +1 < width {
+          pairs.push((Pos{x, y}, Pos{x: x +1, y}));
+        }
+        if y +1 < height {
+          pairs.push((Pos{x, y}, Pos{x, y: y +1}));
+        }
+      }
+    }
+    pairs.shuffle(rng);
+
+    for (pos_a, pos_b) in pairs.iter() {
+
+      // Increment the number of iterations
+      nb_iter_create += 1;
+
+      let set_a = find_set(&mut sets, pos_a.y*width + pos_a.x);
+      let set_b = find_set(&mut sets, pos_b.y*width + pos_b.x);
+      if set_a != set_b {
+        sets[set_b] = set_a;
+        connect(&mut maze, pos_a, pos_b);
+        if let Some(show_generation) = show_generation.as_mut() {
+          show_generation(nb_iter_create, &maze);
+        }
+      }
+    }
+
+    Ok((maze, nb_iter_create))
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Retrieve the set of a given node, flattening the chain of sets along the way
+// ----------------------------------------------------------------
+fn find_set(sets: &mut [usize], idx: usize) -> usize {
+  let mut root = idx;
+  while sets[root] != root {
+    root = sets[root];
+  }
+  let mut idx = idx;
+  while sets[idx] != root {
+    let idx_next = sets[idx];
+    sets[idx] = root;
+    idx = idx_next;
+  }
+  root
+}
@@ -0,0 +1,81 @@
+use ndarray::Array2;
+use rand::rngs::StdRng;
+
+use crate::{Direction, MazeError, Openings, Pos};
+
+mod aldous_broder;
+mod eller;
+mod kruskal;
+mod prim;
+mod recursive_backtracker;
+mod recursive_division;
+mod wilson;
+
+pub use aldous_broder::AldousBroder;
+pub use eller::{Eller, EllerRows};
+pub use kruskal::Kruskal;
+pub use prim::Prim;
+pub use recursive_backtracker::RecursiveBacktracker;
+pub use recursive_division::RecursiveDivision;
+pub use wilson::Wilson;
+
+
+// ----------------------------------------------------------------
+// Common interface of the maze generation algorithms
+// Each algorithm connects the nodes of a "width"x"height" maze into a spanning tree (namely, a perfect maze)...
+// ...and stores the possible moves from each node via the {L2R, R2L, U2D, D2U} encoding
+// ----------------------------------------------------------------
+pub trait MazeGenerator {
+
+  // Name of the algorithm, as used on the command line
+  fn name(&self) -> &'static str;
+
+  // Return the possible moves from each node, along with the number of iterations of the generation process
+  // The orientation (true: horizontal; false: vertical) may be ignored by algorithms that have no preferred direction
+  // The intermediate steps are passed to "show_generation", if any (see "ascii::write_generation_step")
+  // Mazes of less than two nodes are rejected (see "check_size")
+  fn generate_maze(
+    &self, width: usize, height: usize, orientation: bool, show_generation: Option<ShowGeneration>,
+    rng: &mut StdRng) -> Result<(Array2<u8>, usize), MazeError>;
+}
+
+
+// Callback to observe an intermediate step of the generation, given the number of iterations so far...
+// ...and the possible moves from each node (nodes that have not been connected yet have the code "0")
+pub type ShowGeneration<'a> = &'a mut dyn FnMut(usize, &Array2<u8>);
+
+
+// ----------------------------------------------------------------
+// A single node would be a fully-closed unit-sized room, which cannot be encoded: at least two nodes are needed
+// ----------------------------------------------------------------
+pub fn check_size(width: usize, height: usize) -> Result<(), MazeError> {
+  if width*height < 2 {
+    return Err(MazeError::TooSmall{width, height});
+  }
+  Ok(())
+}
+
+
+// ----------------------------------------------------------------
+// Retrieve the nodes that are direct neighbors of a given node, regardless of walls and doors
+// ----------------------------------------------------------------
+pub(crate) fn get_neighbors(width: usize, height: usize, pos: &Pos) -> Vec<Pos> {
+  Direction::ALL.into_iter()
+    .filter_map(|dir| dir.step(pos))
+    .filter(|neighbor| neighbor.x < width && neighbor.y < height)
+    .collect()
+}
+
+
+// ----------------------------------------------------------------
+// Create a door between two neighbor nodes, by opening the bi-directional move
+// ----------------------------------------------------------------
+pub(crate) fn connect(maze: &mut Array2<u8>, pos_a: &Pos, pos_b: &Pos) {
+  let dir = Direction::between(pos_a, pos_b).expect("Impossible connection");
+  for (pos, dir) in [(pos_a, dir), (pos_b, dir.opposite())] {
+    let mut openings = Openings::from_bits_truncate(maze[[pos.y, pos.x]]);
+    openings.open(dir);
+    maze[[pos.y, pos.x]] = openings.bits();
+  }
+}
+
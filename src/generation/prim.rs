@@ -0,0 +1,74 @@
+use ndarray::Array2;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::{MazeError, Pos};
+use super::{check_size, connect, get_neighbors, MazeGenerator, ShowGeneration};
+
+
+// Randomized Prim's algorithm: many short dead ends, radiating from the starting node
+pub struct Prim;
+
+impl MazeGenerator for Prim {
+
+  fn name(&self) -> &'static str {
+    "prim"
+  }
+
+  // ----------------------------------------------------------------
+  // Grow the maze from a random node: at each iteration, pick a random node of the frontier (namely, a node that is...
+  // ...not yet in the maze but neighbors at least one node of the maze), and connect it to one of its maze neighbors
+  // ----------------------------------------------------------------
+  fn generate_maze(
+    &self, width: usize, height: usize, _orientation: bool, mut show_generation: Option<ShowGeneration>,
+    rng: &mut StdRng) -> Result<(Array2<u8>, usize), MazeError> {
+
+    check_size(width, height)?;
+
+    let mut maze = Array2::<u8>::zeros((height, width));
+    let mut in_maze = Array2::<bool>::from_elem((height, width), false);
+    let mut in_frontier = Array2::<bool>::from_elem((height, width), false);
+    let mut vec_frontier = vec![];
+    let mut nb_iter_create: usize = 0;
+
+    // Start with a random node
+    let mut pos = Pos{x: rng.gen_range(0..width), y: rng.gen_range(0..height)};
+    in_maze[[pos.y, pos.x]] = true;
+
+    loop {
+
+      // Extend the frontier with the neighbors of the newly-added node
+      for neighbor in get_neighbors(width, height, &pos) {
+        if !in_maze[[neighbor.y, neighbor.x]] && !in_frontier[[neighbor.y, neighbor.x]] {
+          in_frontier[[neighbor.y, neighbor.x]] = true;
+          vec_frontier.push(neighbor);
+        }
+      }
+
+      if vec_frontier.is_empty() {
+        break;
+      }
+
+      // Increment the number of iterations
+      nb_iter_create += 1;
+
+      // Pick a random node of the frontier, and connect it to a random neighbor already in the maze
+      let idx_frontier = rng.gen_range(0..vec_frontier.len());
+      pos = vec_frontier.swap_remove(idx_frontier);
+      let maze_neighbors: Vec<Pos> = get_neighbors(width, height, &pos).into_iter()
+        .filter(|neighbor| in_maze[[neighbor.y, neighbor.x]])
+        .collect();
+      let pos_maze = maze_neighbors.choose(rng).unwrap();
+      connect(&mut maze, &pos, pos_maze);
+      in_maze[[pos.y, pos.x]] = true;
+      in_frontier[[pos.y, pos.x]] = false;
+
+      if let Some(show_generation) = show_generation.as_mut() {
+        show_generation(nb_iter_create, &maze);
+      }
+    }
+
+    Ok((maze, nb_iter_create))
+  }
+}
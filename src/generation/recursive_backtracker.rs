@@ -0,0 +1,66 @@
+use ndarray::Array2;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::{MazeError, Pos};
+use super::{check_size, connect, get_neighbors, MazeGenerator, ShowGeneration};
+
+
+// Recursive backtracker (randomized depth-first search): long and winding corridors, with few dead ends
+pub struct RecursiveBacktracker;
+
+impl MazeGenerator for RecursiveBacktracker {
+
+  fn name(&self) -> &'static str {
+    "recursive-backtracker"
+  }
+
+  // ----------------------------------------------------------------
+  // Walk from a random node towards a random unvisited neighbor, as long as there is one...
+  // ...otherwise backtrack to the previous node of the walk, until every node has been visited
+  // The recursion is unrolled with an explicit stack, so large mazes do not overflow the call stack
+  // ----------------------------------------------------------------
+  fn generate_maze(
+    &self, width: usize, height: usize, _orientation: bool, mut show_generation: Option<ShowGeneration>,
+    rng: &mut StdRng) -> Result<(Array2<u8>, usize), MazeError> {
+
+    check_size(width, height)?;
+
+    let mut maze = Array2::<u8>::zeros((height, width));
+    let mut visited = Array2::<bool>::from_elem((height, width), false);
+    let mut nb_iter_create: usize = 0;
+
+    // Start the walk at a random node
+    let pos_start = Pos{x: rng.gen_range(0..width), y: rng.gen_range(0..height)};
+    visited[[pos_start.y, pos_start.x]] = true;
+    let mut stack = vec![pos_start];
+
+    while let Some(pos) = stack.last().copied() {
+
+      // Increment the number of iterations
+      nb_iter_create += 1;
+
+      let unvisited_neighbors: Vec<Pos> = get_neighbors(width, height, &pos).into_iter()
+        .filter(|neighbor| !visited[[neighbor.y, neighbor.x]])
+        .collect();
+
+      match unvisited_neighbors.choose(rng) {
+        Some(next) => {
+          connect(&mut maze, &pos, next);
+          visited[[next.y, next.x]] = true;
+          stack.push(*next);
+          if let Some(show_generation) = show_generation.as_mut() {
+            show_generation(nb_iter_create, &maze);
+          }
+        },
+        None => {
+          // Dead end: backtrack
+          stack.pop();
+        }
+      }
+    }
+
+    Ok((maze, nb_iter_create))
+  }
+}
@@ -0,0 +1,162 @@
+use ndarray::Array2;
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::{L2R, R2L, U2D, D2U, MazeError};
+use crate::ascii::HEX_F_CODE;
+use super::{check_size, MazeGenerator, ShowGeneration};
+
+
+// Recursive division: the original generator, producing long straight walls
+pub struct RecursiveDivision;
+
+impl MazeGenerator for RecursiveDivision {
+
+  fn name(&self) -> &'static str {
+    "recursive-division"
+  }
+
+  fn generate_maze(
+    &self, width: usize, height: usize, orientation: bool, show_generation: Option<ShowGeneration>,
+    rng: &mut StdRng) -> Result<(Array2<u8>, usize), MazeError> {
+    check_size(width, height)?;
+    Ok(generate_maze(width, height, orientation, show_generation, rng))
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Generate a random "width"x"height" maze via recursive splitting
+// Return the possible moves from each node, along with the number of iterations of the recursive process
+// ----------------------------------------------------------------
+fn generate_maze(
+  width: usize, height: usize, orientation: bool, mut show_generation: Option<ShowGeneration>,
+  rng: &mut StdRng) -> (Array2<u8>, usize) {
+
+  // Create a width*height maze array (rows along the height, columns along the width)
+  let mut maze = Array2::<u8>::zeros((height, width));
+  let mut maze_opposite = Array2::<u8>::zeros((height, width));
+
+  // Keep track of the intermediate states to show the generation process
+  // This variable is "opposite" to the maze because in this process, maze_opposite are added instead of connections
+  for x in 0..width {
+    for y in 0..height {
+      maze_opposite[[y, x]] = HEX_F_CODE;
+      if x == 0 {
+        maze_opposite[[y, x]] -= R2L;
+      }
+      if x == width -1 {
+        maze_opposite[[y, x]] -= L2R;
+      }
+      if y == 0 {
+        maze_opposite[[y, x]] -= D2U;
+      }
+      if y == height -1 {
+        maze_opposite[[y, x]] -= U2D;
+      }
+    }
+  }
+  if let Some(show_generation) = show_generation.as_mut() {
+    show_generation(0, &maze_opposite);
+  }
+
+  // Create the maze: uppermost call to the recursive process (independent of the position of the entrance and goal)
+  // The walls are first positioned along the width for a horizontal orientation, and along the height otherwise
+  let (wall_max, door_max) = if orientation {(width-1, height-1)} else {(height-1, width-1)};
+  let mut nb_iter_create: usize = 0;
+  recursive_region_splitting(
+    &mut maze, &mut maze_opposite, 0, wall_max, 0, door_max, orientation, &mut nb_iter_create,
+    &mut show_generation, rng);
+
+  (maze, nb_iter_create)
+}
+
+
+// ----------------------------------------------------------------
+// Recursively generate the maze by splitting a region in two sub-regions...
+// ...and creating a connection between two neighbor nodes from each region
+// The implicit stop condition for this recursive process is: "wall_max == wall_min && door_max == door_min"
+// ----------------------------------------------------------------
+#[allow(clippy::too_many_arguments)]
+fn recursive_region_splitting(
+  maze: &mut Array2<u8>, maze_opposite: &mut Array2<u8>, wall_min: usize, wall_max: usize, door_min: usize,
+  door_max: usize, orientation: bool, nb_iter_create: &mut usize, show_generation: &mut Option<ShowGeneration>,
+  rng: &mut StdRng) {
+
+  // Increment the number of iterations
+  *nb_iter_create += 1;
+
+  // Subdivide the room
+  if wall_max > wall_min {
+
+    // Randomly determine the wall position
+    let wall_pos = rng.gen_range(wall_min..wall_max);
+    let wall_pos_plus_one = wall_pos +1;
+
+    // Randomly determine the door position
+    let door_pos = if door_max > door_min {
+      rng.gen_range(door_min..door_max)
+    } else {
+      door_min
+    };
+
+    // Determine the code-word for the bi-directional move through the door in function of the current orientation
+    match orientation {
+      true => { // vertical separation, horizontal move
+        maze[[door_pos, wall_pos]] += L2R;
+        maze[[door_pos, wall_pos +1]] += R2L;
+        // The following is only needed to display the intermediate steps during maze generation
+        for y in door_min..door_pos {
+          maze_opposite[[y, wall_pos]] -= L2R;
+          maze_opposite[[y, wall_pos +1]] -= R2L;
+        }
+        for y in door_pos+1..door_max+1 {
+          maze_opposite[[y, wall_pos]] -= L2R;
+          maze_opposite[[y, wall_pos +1]] -= R2L;
+        }
+      },
+      false => { // horizontal separation, vertical move
+        maze[[wall_pos, door_pos]] += U2D;
+        maze[[wall_pos +1, door_pos]] += D2U;
+        // The following is only needed to display the intermediate steps during maze generation
+        for x in door_min..door_pos {
+          maze_opposite[[wall_pos, x]] -= U2D;
+          maze_opposite[[wall_pos +1, x]] -= D2U;
+        }
+        for x in door_pos+1..door_max+1 {
+          maze_opposite[[wall_pos, x]] -= U2D;
+          maze_opposite[[wall_pos +1, x]] -= D2U;
+        }
+      }
+    }
+
+    // Display the intermediate steps
+    if let Some(show_generation) = show_generation.as_mut() {
+      show_generation(*nb_iter_create, maze_opposite);
+    }
+
+    // Two recursive calls, on the regions in both sides of the wall
+    if door_max > door_min {
+      // Call with flipped orientation: the room is large enough to be subdivided along the other orientation
+      recursive_region_splitting(
+        maze, maze_opposite, door_min, door_max, wall_min, wall_pos, !orientation, nb_iter_create,
+        show_generation, rng);
+      recursive_region_splitting(
+        maze, maze_opposite, door_min, door_max, wall_pos_plus_one, wall_max, !orientation, nb_iter_create,
+        show_generation, rng);
+    } else if door_max == door_min {
+      // Call with same orientation: the room cannot be subdivided along the other orientation
+      recursive_region_splitting(
+        maze, maze_opposite, wall_min, wall_pos, door_min, door_max, orientation, nb_iter_create,
+        show_generation, rng);
+      recursive_region_splitting(
+        maze, maze_opposite, wall_pos_plus_one, wall_max, door_min, door_max, orientation, nb_iter_create,
+        show_generation, rng);
+    }
+
+  // Recursive call on the same room with flipped orientation
+  } else if wall_max == wall_min && door_max > door_min {
+    recursive_region_splitting(
+      maze, maze_opposite, door_min, door_max, wall_min, wall_max, !orientation, nb_iter_create, show_generation, rng);
+  }
+}
@@ -0,0 +1,65 @@
+use ndarray::Array2;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::{MazeError, Pos};
+use super::{check_size, connect, get_neighbors, MazeGenerator, ShowGeneration};
+
+
+// Wilson's algorithm: loop-erased random walks, yielding an unbiased sample among all the possible perfect mazes
+pub struct Wilson;
+
+impl MazeGenerator for Wilson {
+
+  fn name(&self) -> &'static str {
+    "wilson"
+  }
+
+  // ----------------------------------------------------------------
+  // Start with a single random node in the maze, then, from each node that is not in the maze yet, randomly walk...
+  // ...until a node of the maze is hit, and connect the nodes along the walk, once its loops have been erased
+  // Loops are implicitly erased by only remembering the last move taken from each node of the walk
+  // ----------------------------------------------------------------
+  fn generate_maze(
+    &self, width: usize, height: usize, _orientation: bool, mut show_generation: Option<ShowGeneration>,
+    rng: &mut StdRng) -> Result<(Array2<u8>, usize), MazeError> {
+
+    check_size(width, height)?;
+
+    let mut maze = Array2::<u8>::zeros((height, width));
+    let mut in_maze = Array2::<bool>::from_elem((height, width), false);
+    let mut last_move = Array2::<Pos>::from_elem((height, width), Pos{x: 0, y: 0});
+    let mut nb_iter_create: usize = 0;
+
+    in_maze[[rng.gen_range(0..height), rng.gen_range(0..width)]] = true;
+
+    for y in 0..height {
+      for x in 0..width {
+
+        // Random walk until the maze is hit
+        let mut pos = Pos{x, y};
+        while !in_maze[[pos.y, pos.x]] {
+          nb_iter_create += 1;
+          let next = *get_neighbors(width, height, &pos).choose(rng).unwrap();
+          last_move[[pos.y, pos.x]] = next;
+          pos = next;
+        }
+
+        // Connect the nodes along the loop-erased walk
+        let mut pos = Pos{x, y};
+        while !in_maze[[pos.y, pos.x]] {
+          let next = last_move[[pos.y, pos.x]];
+          connect(&mut maze, &pos, &next);
+          in_maze[[pos.y, pos.x]] = true;
+          pos = next;
+          if let Some(show_generation) = show_generation.as_mut() {
+            show_generation(nb_iter_create, &maze);
+          }
+        }
+      }
+    }
+
+    Ok((maze, nb_iter_create))
+  }
+}
@@ -0,0 +1,85 @@
+use ndarray::Array2;
+
+use crate::{Maze, MazeError, Pos};
+
+
+// ----------------------------------------------------------------
+// Write the maze in a compact text format: a header line with the orientation and the positions of the entrance...
+// ...and the goal, followed by one line per row of the maze, with the hexadecimal code of each node, e.g.:
+// orientation=horizontal entrance=0,1 goal=2,0
+// 536
+// 928
+// ----------------------------------------------------------------
+pub fn maze_to_hex_string(maze: &Maze) -> String {
+  let mut hex_string = format!(
+    "orientation={} entrance={},{} goal={},{}\n",
+    if maze.orientation {"horizontal"} else {"vertical"},
+    maze.pos_entrance.x, maze.pos_entrance.y, maze.pos_goal.x, maze.pos_goal.y);
+  for row in maze.codes.rows() {
+    for code in row.iter() {
+      hex_string.push_str(&format!("{:X}", code));
+    }
+    hex_string.push('\n');
+  }
+  hex_string
+}
+
+
+// ----------------------------------------------------------------
+// Read a maze written in the compact text format, and check that its codes are consistent
+// The fields of the header line may come in any order, and blank lines are ignored
+// ----------------------------------------------------------------
+pub fn maze_from_hex_string(hex_string: &str) -> Result<Maze, MazeError> {
+
+  let mut lines = hex_string.lines().map(str::trim).filter(|line| !line.is_empty());
+
+  // Header line
+  let header = lines.next().ok_or(MazeError::Parse(String::from("empty maze")))?;
+  let (mut orientation, mut pos_entrance, mut pos_goal) = (None, None, None);
+  for field in header.split_whitespace() {
+    match field.split_once('=') {
+      Some(("orientation", "horizontal")) => orientation = Some(true),
+      Some(("orientation", "vertical")) => orientation = Some(false),
+      Some(("entrance", pos)) => pos_entrance = Some(parse_pos(pos)?),
+      Some(("goal", pos)) => pos_goal = Some(parse_pos(pos)?),
+      _ => return Err(MazeError::Parse(format!("invalid header field `{}`", field)))
+    }
+  }
+  let missing = |field: &str| MazeError::Parse(format!("the {} is missing from the header", field));
+  let orientation = orientation.ok_or_else(|| missing("orientation"))?;
+  let pos_entrance = pos_entrance.ok_or_else(|| missing("entrance"))?;
+  let pos_goal = pos_goal.ok_or_else(|| missing("goal"))?;
+
+  // One line of hexadecimal digits per row
+  let rows: Vec<Vec<char>> = lines.map(|line| line.chars().collect()).collect();
+  let (width, height) = (rows.first().map_or(0, |row| row.len()), rows.len());
+  let mut codes = Array2::<u8>::zeros((height, width));
+  for (y, row) in rows.iter().enumerate() {
+    if row.len() != width {
+      return Err(MazeError::Parse(format!("row {} has {} codes instead of {}", y, row.len(), width)));
+    }
+    for (x, digit) in row.iter().enumerate() {
+      codes[[y, x]] = match digit.to_digit(16) {
+        Some(code) if code > 0 => code as u8,
+        _ => return Err(MazeError::Parse(format!("invalid code `{}` at ({},{}) (expected 1 to F)", digit, x, y)))
+      };
+    }
+  }
+
+  let maze = Maze{
+    width, height, codes, pos_entrance, pos_goal, orientation, generator: String::from("unknown"), seed: None,
+    nb_iter_create: 0};
+  maze.check_consistency()?;
+  Ok(maze)
+}
+
+
+// ----------------------------------------------------------------
+// Read a position written as "x,y"
+// ----------------------------------------------------------------
+fn parse_pos(pos: &str) -> Result<Pos, MazeError> {
+  match pos.split_once(',').and_then(|(x, y)| Some((x.parse().ok()?, y.parse().ok()?))) {
+    Some((x, y)) => Ok(Pos{x, y}),
+    None => Err(MazeError::Parse(format!("invalid position `{}` (expected x,y)", pos)))
+  }
+}
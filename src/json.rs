@@ -0,0 +1,95 @@
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+
+use crate::{Maze, MazeError, Pos};
+
+
+// Layout of a maze in the JSON format, the codes being stored row by row as strings of hexadecimal digits
+#[derive(Serialize, Deserialize)]
+struct MazeJson {
+  width: usize,
+  height: usize,
+  orientation: String,
+  entrance: Pos,
+  goal: Pos,
+  seed: Option<u64>,
+  generator: String,
+  codes: Vec<String>
+}
+
+
+// ----------------------------------------------------------------
+// Write the maze in the JSON format, e.g. for a 3x2 horizontal maze:
+// {"width": 3, "height": 2, "orientation": "horizontal", "entrance": {"x": 0, "y": 1}, "goal": {"x": 2, "y": 0},
+//  "seed": 893, "generator": "recursive-division", "codes": ["536", "928"]}
+// ----------------------------------------------------------------
+pub fn maze_to_json(maze: &Maze) -> String {
+  let maze_json = MazeJson{
+    width: maze.width,
+    height: maze.height,
+    orientation: String::from(if maze.orientation {"horizontal"} else {"vertical"}),
+    entrance: maze.pos_entrance,
+    goal: maze.pos_goal,
+    seed: maze.seed,
+    generator: maze.generator.clone(),
+    codes: maze.codes.rows().into_iter()
+      .map(|row| row.iter().map(|code| format!("{:X}", code)).collect())
+      .collect()
+  };
+  serde_json::to_string_pretty(&maze_json).expect("A maze can always be serialized")
+}
+
+
+// ----------------------------------------------------------------
+// Read a maze written in the JSON format, and check that its codes are consistent
+// ----------------------------------------------------------------
+pub fn maze_from_json(json: &str) -> Result<Maze, MazeError> {
+
+  let maze_json: MazeJson = serde_json::from_str(json)
+    .map_err(|error| MazeError::Parse(format!("invalid maze: {}", error)))?;
+  let (width, height) = (maze_json.width, maze_json.height);
+
+  let orientation = match maze_json.orientation.as_str() {
+    "horizontal" => true,
+    "vertical" => false,
+    orientation => return Err(MazeError::Parse(
+      format!("invalid orientation `{}` (expected horizontal or vertical)", orientation)))
+  };
+
+  // Decode the hexadecimal digits, row by row
+  if maze_json.codes.len() != height {
+    return Err(MazeError::Parse(
+      format!("{} rows of codes found, whereas the height is {}", maze_json.codes.len(), height)));
+  }
+  // The size of every row is checked before allocating the codes, so a bogus width cannot exhaust the memory
+  for (y, row) in maze_json.codes.iter().enumerate() {
+    if row.chars().count() != width {
+      return Err(MazeError::Parse(
+        format!("{} codes found in row {}, whereas the width is {}", row.chars().count(), y, width)));
+    }
+  }
+  let mut codes = Array2::<u8>::zeros((height, width));
+  for (y, row) in maze_json.codes.iter().enumerate() {
+    for (x, digit) in row.chars().enumerate() {
+      codes[[y, x]] = match digit.to_digit(16) {
+        Some(code) if code > 0 => code as u8,
+        _ => return Err(MazeError::Parse(format!("invalid code `{}` at ({},{}) (expected 1 to F)", digit, x, y)))
+      };
+    }
+  }
+
+  let maze = Maze{
+    width,
+    height,
+    codes,
+    pos_entrance: maze_json.entrance,
+    pos_goal: maze_json.goal,
+    orientation,
+    generator: maze_json.generator,
+    seed: maze_json.seed,
+    nb_iter_create: 0
+  };
+  maze.check_consistency()?;
+  Ok(maze)
+}
+
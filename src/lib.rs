@@ -0,0 +1,316 @@
+use std::io::Write;
+
+use ndarray::Array2;
+use rand::Rng;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+pub mod animation;
+pub mod ascii;
+pub mod box_drawing;
+pub mod braiding;
+pub mod constraints;
+pub mod direction;
+pub mod distance;
+pub mod error;
+pub mod generation;
+pub mod hex;
+pub mod json;
+pub mod metrics;
+pub mod placement;
+pub mod raster;
+pub mod solving;
+pub mod svg;
+pub mod terminal;
+pub mod theme;
+pub mod validation;
+
+pub use animation::Animation;
+pub use constraints::{Constraint, ConstraintReport, Metric};
+pub use direction::{Direction, Openings};
+pub use error::MazeError;
+pub use generation::{MazeGenerator, ShowGeneration};
+pub use metrics::MazeMetrics;
+pub use placement::{Gate, Placement};
+pub use raster::{RasterOptions, RgbImage};
+pub use solving::{ShowSolving, Solution, Solver};
+pub use svg::SvgOptions;
+pub use theme::Theme;
+pub use validation::ValidationError;
+
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~ The following parameters shall not be modified ~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+
+// Code-words for the four possible directions
+static BASE: u8 = 2;
+pub static L2R: u8 = BASE.pow(0); // (:=1) code for possible "left to right" move
+pub static R2L: u8 = BASE.pow(1); // (:=2) code for possible "right to left" move
+pub static U2D: u8 = BASE.pow(2); // (:=4) code for possible "up to down" move
+pub static D2U: u8 = BASE.pow(3); // (:=8) code for possible "down to up" move
+
+// 2D coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pos {
+  pub x: usize,
+  pub y: usize
+}
+
+
+// ----------------------------------------------------------------
+// A "width"x"height" maze, storing the possible moves from each node via an unambiguous hexadecimal encoding
+// The orientation is true for a horizontal maze (entrance on the left, goal on the right)...
+// ...and false for a vertical maze (entrance on the top, goal on the bottom), unless they are placed elsewhere...
+// ...(see "Maze::place"), in which case it only tells which side they open on when they lie in a corner
+// The name of the generation algorithm and the random seed (if known) are kept for reproducibility
+// ----------------------------------------------------------------
+#[derive(Debug, Clone)]
+pub struct Maze {
+  pub width: usize,
+  pub height: usize,
+  pub codes: Array2<u8>,
+  pub pos_entrance: Pos,
+  pub pos_goal: Pos,
+  pub orientation: bool,
+  pub generator: String,
+  pub seed: Option<u64>,
+  pub nb_iter_create: usize
+}
+
+
+impl Maze {
+
+  // ----------------------------------------------------------------
+  // Generate a random "width"x"height" maze with the given algorithm, with the entrance and goal on two opposed sides
+  // The intermediate steps are passed to "show_generation", if any (see "ascii::write_generation_step")
+  // A single node would be a fully-closed unit-sized room, which cannot be encoded: at least two nodes are needed
+  // ----------------------------------------------------------------
+  pub fn generate(
+    width: usize, height: usize, orientation: bool, generator: &dyn MazeGenerator,
+    show_generation: Option<ShowGeneration>, rng: &mut StdRng) -> Result<Maze, MazeError> {
+
+    generation::check_size(width, height)?;
+
+    // Random choice for the entrance
+    let pos_entrance = if orientation {
+      Pos{x: 0, y: rng.gen_range(0..height)} // entrance on the left-side wall
+    } else {
+      Pos{x: rng.gen_range(0..width), y: 0} // entrance on the top-side wall
+    };
+
+    // Random choice for the goal
+    let pos_goal = if orientation {
+      Pos{x: width-1, y: rng.gen_range(0..height)} // goal on the right-side wall
+    } else {
+      Pos{x: rng.gen_range(0..width), y: height-1} // goal on the bottom-side wall
+    };
+
+    let (codes, nb_iter_create) = generator.generate_maze(width, height, orientation, show_generation, rng)?;
+
+    Ok(Maze{
+      width, height, codes, pos_entrance, pos_goal, orientation, generator: String::from(generator.name()), seed: None,
+      nb_iter_create})
+  }
+
+
+  // ----------------------------------------------------------------
+  // Generate random mazes until one meets all the constraints (e.g. a solution through at least 60% of the nodes)...
+  // ...or until "max_attempts" mazes have been generated, the closest one being returned along with a report
+  // See "constraints::generate_with_constraints"
+  // ----------------------------------------------------------------
+  #[allow(clippy::too_many_arguments)]
+  pub fn generate_with_constraints(
+    width: usize, height: usize, orientation: bool, generator: &dyn MazeGenerator, placement: Option<&Placement>,
+    constraints: &[Constraint], max_attempts: usize, rng: &mut StdRng) -> Result<(Maze, ConstraintReport), MazeError> {
+    constraints::generate_with_constraints(
+      width, height, orientation, generator, placement, constraints, max_attempts, rng)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Move the entrance and the goal with the given strategy (e.g. to the two ends of the longest path)
+  // See "placement::place_entrance_and_goal"
+  // ----------------------------------------------------------------
+  pub fn place(&mut self, placement: &Placement, rng: &mut StdRng) -> Result<(), MazeError> {
+    (self.pos_entrance, self.pos_goal) =
+      placement::place_entrance_and_goal(&self.codes, self.orientation, placement, rng)?;
+    Ok(())
+  }
+
+
+  // Entrance, along with the side of the outer wall it opens on (see "placement::Gate")
+  pub fn entrance_gate(&self) -> Gate {
+    Gate::entrance(self.pos_entrance, self.width, self.height, self.orientation)
+  }
+
+
+  // Goal, along with the side of the outer wall it opens on (see "placement::Gate")
+  pub fn goal_gate(&self) -> Gate {
+    Gate::goal(self.pos_goal, self.width, self.height, self.orientation)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Turn the perfect maze into a maze with loops, by removing a fraction (between 0 and 1) of its dead ends
+  // Return the number of doors that have been opened
+  // ----------------------------------------------------------------
+  pub fn braid(&mut self, fraction: f64, rng: &mut StdRng) -> usize {
+    braiding::braid_maze(&mut self.codes, fraction, rng)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Solve the maze via front-propagation with the given search strategy...
+  // ...and extract the corresponding path via backtracking
+  // The intermediate steps are passed to "show_solving", if any (see "ascii::write_propagation_step")
+  // Inconsistent mazes are rejected beforehand (see "Maze::validate")
+  // ----------------------------------------------------------------
+  pub fn solve(
+    &self, solver: &dyn Solver, show_solving: Option<ShowSolving>, rng: &mut StdRng) -> Result<Solution, MazeError> {
+    solving::solve_maze(self, solver, show_solving, rng)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Number of moves needed to reach each node from a given node, if any (see "distance::get_distance_map")
+  // ----------------------------------------------------------------
+  pub fn distances_from(&self, pos_origin: &Pos) -> Result<Array2<Option<usize>>, MazeError> {
+    self.check_consistency()?;
+    if pos_origin.x >= self.width || pos_origin.y >= self.height {
+      return Err(MazeError::OutOfBounds{pos: *pos_origin});
+    }
+    Ok(distance::get_distance_map(&self.codes, pos_origin))
+  }
+
+
+  // ----------------------------------------------------------------
+  // Measure the difficulty of the maze, along with its shortest path (see "metrics::MazeMetrics")
+  // ----------------------------------------------------------------
+  pub fn metrics(&self, solution: &Solution) -> Result<MazeMetrics, MazeError> {
+    self.check_consistency()?;
+    metrics::compute_metrics(self, &solution.path)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Display the maze in ascii-art, each node being drawn with the symbol given by "symbol_code"
+  // The maze is written to "out", e.g. "std::io::stdout()" for the console, or a file
+  // ----------------------------------------------------------------
+  pub fn render(&self, out: &mut impl Write, symbol_code: &Array2<u8>, theme: &Theme) -> Result<(), MazeError> {
+    ascii::write_maze_in_ascii(out, &self.codes, &self.entrance_gate(), &self.goal_gate(), symbol_code, theme)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Draw the maze as an SVG image, along with the shortest path and the search state of a solution, if any
+  // ----------------------------------------------------------------
+  pub fn to_svg(&self, solution: Option<&Solution>, options: &SvgOptions) -> Result<String, MazeError> {
+    self.check_drawable(solution, options.distance_origin.as_ref())?;
+    Ok(svg::maze_to_svg(self, solution, options))
+  }
+
+
+  // ----------------------------------------------------------------
+  // Draw the maze as a bitmap image (with the same layout as in the console), along with the shortest path and...
+  // ...the search state of a solution, if any
+  // ----------------------------------------------------------------
+  pub fn to_image(&self, solution: Option<&Solution>, options: &RasterOptions) -> Result<RgbImage, MazeError> {
+    self.check_drawable(solution, options.distance_origin.as_ref())?;
+    Ok(raster::maze_to_image(self, solution, options))
+  }
+
+
+  // ----------------------------------------------------------------
+  // Check that the maze can be drawn as an image: its codes shall be consistent, the origin of the heat map (if any)...
+  // ...shall lie within the maze, and so shall the solution (if any), which may have been found for another maze
+  // ----------------------------------------------------------------
+  fn check_drawable(&self, solution: Option<&Solution>, distance_origin: Option<&Pos>) -> Result<(), MazeError> {
+    self.check_consistency()?;
+    let is_outside = |pos: &&Pos| pos.x >= self.width || pos.y >= self.height;
+    if let Some(pos) = distance_origin.filter(is_outside) {
+      return Err(MazeError::OutOfBounds{pos: *pos});
+    }
+    let Some(solution) = solution else {
+      return Ok(());
+    };
+    if let Some(pos) = solution.path.iter().find(is_outside) {
+      return Err(MazeError::OutOfBounds{pos: *pos});
+    }
+    if let Some(step) = solution.path.windows(2).find(|step| Direction::between(&step[0], &step[1]).is_none()) {
+      return Err(MazeError::InvalidPath{pos_a: step[0], pos_b: step[1]});
+    }
+    for region in [&solution.alv_region, &solution.nar_region, &solution.far_region] {
+      let (nb_rows, nb_columns) = region.dim();
+      if nb_columns < self.width {
+        return Err(MazeError::InvalidRegions{pos: Pos{x: nb_columns, y: 0}});
+      }
+      if nb_rows < self.height {
+        return Err(MazeError::InvalidRegions{pos: Pos{x: 0, y: nb_rows}});
+      }
+    }
+    Ok(())
+  }
+
+
+  // ----------------------------------------------------------------
+  // Write the maze in the JSON format (see "json::maze_to_json")
+  // ----------------------------------------------------------------
+  pub fn to_json(&self) -> String {
+    json::maze_to_json(self)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Read a maze written in the JSON format, rejecting inconsistent codes
+  // ----------------------------------------------------------------
+  pub fn from_json(json: &str) -> Result<Maze, MazeError> {
+    json::maze_from_json(json)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Check the consistency of the maze, reporting every inconsistency found (see "validation::validate_maze")
+  // If "expect_perfect" holds, the maze shall also have no loop (e.g. before braiding)
+  // ----------------------------------------------------------------
+  pub fn validate(&self, expect_perfect: bool) -> Result<(), Vec<ValidationError>> {
+    let errors = validation::validate_maze(self, expect_perfect);
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+
+  // ----------------------------------------------------------------
+  // Check the consistency of a maze that has been read from a file (or is about to be solved), loops being allowed
+  // ----------------------------------------------------------------
+  pub(crate) fn check_consistency(&self) -> Result<(), MazeError> {
+    self.validate(false).map_err(MazeError::Inconsistent)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Write the maze in a compact text format, with one line of hexadecimal codes per row (see "hex::maze_to_hex_string")
+  // ----------------------------------------------------------------
+  pub fn to_hex_string(&self) -> String {
+    hex::maze_to_hex_string(self)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Read a maze written in the compact text format, rejecting inconsistent codes
+  // ----------------------------------------------------------------
+  pub fn from_hex_string(hex_string: &str) -> Result<Maze, MazeError> {
+    hex::maze_from_hex_string(hex_string)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Read a maze printed in the console in ascii-art (see "ascii::parse_maze_from_ascii")
+  // ----------------------------------------------------------------
+  pub fn from_ascii(text: &str) -> Result<Maze, MazeError> {
+    ascii::parse_maze_from_ascii(text)
+  }
+}
@@ -1,805 +1,734 @@
-use ndarray::Array2;
+use clap::{CommandFactory, Parser, ValueEnum};
+use clap::error::ErrorKind;
+use std::fs::File;
+use std::fmt::Display;
+use std::io::{self, stdout, BufWriter, IsTerminal};
+use std::path::{Path, PathBuf};
+use ndarray::{Array1, ArrayView1};
 use rand::Rng;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
-use colored::Colorize;
+
+use random_maze::{
+  Animation, Constraint, Direction, Gate, Maze, MazeError, MazeGenerator, Placement, Pos, RasterOptions, ShowGeneration,
+  ShowSolving, Solution, Solver, SvgOptions, Theme};
+use random_maze::ascii::{
+  get_codenames_for_floorplan, write_backtracking_result, write_generation_step, write_maze_bottom_in_ascii,
+  write_distance_map, write_maze_row_in_ascii, write_propagation_result, write_propagation_step,
+  write_propagation_summary, NOD_CODE};
+use random_maze::box_drawing::write_maze_row_in_box_drawing;
+use random_maze::raster::{generation_step_to_image, propagation_step_to_image};
+use random_maze::terminal::TerminalAnimation;
+use random_maze::generation::{
+  check_size, AldousBroder, Eller, Kruskal, Prim, RecursiveBacktracker, RecursiveDivision, Wilson};
+use random_maze::solving::{AStar, BreadthFirst, DepthFirst, Dijkstra, RandomFront};
 
 
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 // ~~~ The following parameters control the process tuning ~~~~~~~~
 // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
 
-// Size of the maze
-static DIM: usize = 7;
-
-// Maze orientation ("horizontal", "vertical", "random")
-static MAZE_ORIENTATION: &'static str = "random";
+/// Generate a random maze, solve it via front propagation, and display the process in the console in ascii-art
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+
+  /// Size of the maze (number of nodes along each side), unless overridden by the width and/or the height
+  #[arg(long, default_value_t = 7, value_parser = parse_size)]
+  size: usize,
+
+  /// Width of the maze (number of nodes along the horizontal side)
+  #[arg(long, value_parser = parse_size)]
+  width: Option<usize>,
+
+  /// Height of the maze (number of nodes along the vertical side)
+  #[arg(long, value_parser = parse_size)]
+  height: Option<usize>,
+
+  /// Maze orientation
+  #[arg(long, value_enum, default_value_t = Orientation::Random)]
+  orientation: Orientation,
+
+  /// Algorithm to generate the maze
+  #[arg(long, value_enum, default_value_t = Generator::RecursiveDivision)]
+  generator: Generator,
+
+  /// Where to place the entrance and the goal (opposite-sides: as generated, or as loaded)
+  #[arg(long, value_enum, default_value_t = PlacementStrategy::OppositeSides)]
+  placement: PlacementStrategy,
+
+  /// Node of the entrance "x,y", along with the node of the goal, instead of a placement strategy
+  #[arg(long, value_name = "X,Y", value_parser = parse_node, requires = "goal", conflicts_with = "placement")]
+  entrance: Option<Pos>,
+
+  /// Node of the goal "x,y", along with the node of the entrance
+  #[arg(long, value_name = "X,Y", value_parser = parse_node, requires = "entrance")]
+  goal: Option<Pos>,
+
+  /// Fraction of the dead ends to remove by opening extra doors, yielding a maze with loops (between 0 and 1)
+  #[arg(long, default_value_t = 0.0, value_parser = parse_fraction)]
+  braid: f64,
+
+  /// Search strategy of the front propagation used to solve the maze
+  #[arg(long, value_enum, default_value_t = SolverStrategy::Random)]
+  solver: SolverStrategy,
+
+  /// Random seed for reproducibility (Japanese speakers will know what 893 stands for ;)
+  #[arg(long)]
+  seed: Option<u64>,
+
+  /// Intermediate display of the maze generation steps
+  #[arg(long)]
+  show_generation: bool,
+
+  /// Intermediate display of the front propagation steps
+  #[arg(long)]
+  show_solving: bool,
+
+  /// Redraw the intermediate steps in place instead of printing them one below the other
+  /// (space: pause/resume, n: next step while paused, q: skip to the end)
+  #[arg(long)]
+  animate: bool,
+
+  /// Number of intermediate steps redrawn per second
+  #[arg(long, default_value_t = 10.0, value_parser = parse_frame_rate)]
+  frame_rate: f64,
+
+  /// Print a vertical maze row by row while it is generated with Eller's algorithm, without solving it
+  /// (the maze is unbounded unless a height is given)
+  #[arg(long, conflicts_with_all = [
+    "orientation", "generator", "placement", "entrance", "braid", "solver", "show_generation", "show_solving",
+    "animate"])]
+  stream: bool,
+
+  /// Load the maze from a file (.json, .hex), or from its ascii-art (.txt), instead of generating it
+  #[arg(long, value_parser = parse_input, conflicts_with_all = [
+    "size", "width", "height", "orientation", "generator", "show_generation", "stream"])]
+  input: Option<PathBuf>,
+
+  /// Symbols used to print the maze in the console in ascii-art
+  #[arg(long, value_enum, default_value_t = AsciiStyle::Fancy)]
+  ascii: AsciiStyle,
+
+  /// Load the symbols (and their colors) used to print the maze from a TOML file, instead of a built-in style
+  #[arg(long, conflicts_with = "ascii")]
+  theme: Option<PathBuf>,
+
+  /// When to print the symbols in color (auto: unless NO_COLOR is set, or the output is not a terminal)
+  #[arg(long, value_enum, default_value_t = ColorMode::Auto)]
+  color: ColorMode,
+
+  /// Also save the maze and its solution as an image (.svg, .png), or the maze alone as a file (.json, .hex)
+  #[arg(long, conflicts_with = "stream", value_parser = parse_output)]
+  output: Option<PathBuf>,
+
+  /// Size of the nodes in the saved SVG image, in pixels
+  #[arg(long, default_value_t = 20.0, value_parser = parse_length)]
+  cell_size: f64,
+
+  /// Thickness of the walls and of the path in the saved SVG image, in pixels
+  #[arg(long, default_value_t = 2.0, value_parser = parse_length)]
+  stroke_width: f64,
+
+  /// Number of pixels along each side of an ascii character in the saved PNG image and GIF animation
+  #[arg(long, default_value_t = 10, value_parser = parse_size)]
+  scale: usize,
+
+  /// Also save the generation and solving steps as an animated GIF
+  #[arg(long, conflicts_with = "stream", value_parser = parse_animation)]
+  animation: Option<PathBuf>,
+
+  /// Duration of each frame of the GIF animation, in milliseconds
+  #[arg(long, default_value_t = 100)]
+  frame_delay: u32,
+
+  /// Shade the nodes of the saved image by their final alive/narrow/far state
+  #[arg(long)]
+  shade_regions: bool,
+
+  /// Also print the distance of each node to the entrance (or to the node "x,y") as a heat map,
+  /// which then shades the nodes of the saved image
+  #[arg(long, value_name = "X,Y", value_parser = parse_node, conflicts_with = "stream")]
+  heat_map: Option<Option<Pos>>,
+
+  /// Also print figures to compare the difficulty of mazes (dead ends, junctions, corridors, turns along the path...)
+  #[arg(long, conflicts_with = "stream")]
+  metrics: bool,
+
+  /// Regenerate the maze until it meets a difficulty constraint (repeatable), written as metric>=min, metric<=max,
+  /// metric=min..max, or metric=value, e.g. solution-ratio>=0.6, dead-ends>=20, or turns=30..50
+  #[arg(long = "constraint", value_name = "CONSTRAINT", value_parser = parse_constraint, conflicts_with_all = [
+    "input", "show_generation", "animation", "braid", "stream"])]
+  constraints: Vec<Constraint>,
+
+  /// Number of mazes generated at most to meet the constraints (the closest one is kept otherwise)
+  #[arg(long, default_value_t = 100, value_parser = parse_size, requires = "constraints")]
+  max_attempts: usize
+}
 
-// Intermediate display
-static SHOW_GENERATION_PROCESS: bool = false;
-static SHOW_SOLVING_PROCESS: bool = false;
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Orientation {
+  Horizontal, // entrance on the left-side wall, goal on the right-side wall
+  Vertical,   // entrance on the top-side wall, goal on the bottom-side wall
+  Random
+}
 
-// Display the different steps of the process in the console either in ascii-art (either fancy, or pure)
-static USE_FANCY_ASCII: bool = true;
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Generator {
+  RecursiveDivision,
+  RecursiveBacktracker,
+  Prim,
+  Kruskal,
+  Wilson,
+  AldousBroder,
+  Eller
+}
 
-// Random seed for reproducibility
-static USE_RANDOM_SEED: bool = false;
-static SEED: u64 = 893; // Japanese speakers will know what this number stands for ;)
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum PlacementStrategy {
+  OppositeSides, // entrance and goal on two opposed sides, given by the orientation
+  SameSide,      // entrance and goal both on the side of the entrance
+  Border,        // entrance and goal anywhere along the outer walls
+  Interior,      // entrance and goal anywhere inside the maze, away from the outer walls
+  MaxDistance    // entrance and goal at both ends of the longest path
+}
 
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum SolverStrategy {
+  Random,
+  BreadthFirst,
+  DepthFirst,
+  Dijkstra,
+  AStar
+}
 
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
-// ~~~ The following parameters shall not be modified ~~~~~~~~~~~~~
-// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum AsciiStyle {
+  Plain,
+  Fancy,
+  Box
+}
 
-// Code-words for the four possible directions
-static BASE: u8 = 2;
-static L2R: u8 = BASE.pow(0); // (:=1) code for possible "left to right" move
-static R2L: u8 = BASE.pow(1); // (:=2) code for possible "right to left" move
-static U2D: u8 = BASE.pow(2); // (:=4) code for possible "up to down" move
-static D2U: u8 = BASE.pow(3); // (:=8) code for possible "down to up" move
-
-// Symbols to draw the maze in ASCII-art
-static ENT_SYMB: &'static str = if USE_FANCY_ASCII {"◆"} else {"E"}; // ("E") entrance
-static GOA_SYMB: &'static str = if USE_FANCY_ASCII {"♥"} else {"G"}; // ("G") goal
-static WAL_SYMB: &'static str = if USE_FANCY_ASCII {"■"} else {"#"}; // ("#") wall
-static NOD_SYMB: &'static str = if USE_FANCY_ASCII {"○"} else {"."}; // (".") generic node
-static OPN_SYMB: &'static str = " "; // opening
-static ALV_SYMB: &'static str = "a"; // node in the alive region
-static NAR_SYMB: &'static str = "n"; // node in the narrow band
-static FAR_SYMB: &'static str = "f"; // node in the far away region
-static PATH_L2R_SYMB: &'static str = if USE_FANCY_ASCII {"→"} else {">"}; // "left to right" move of the shortest path
-static PATH_R2L_SYMB: &'static str = if USE_FANCY_ASCII {"←"} else {"<"}; // "right to left" move of the shortest path
-static PATH_U2D_SYMB: &'static str = if USE_FANCY_ASCII {"↓"} else {"v"}; // "up to down" move of the shortest path
-static PATH_D2U_SYMB: &'static str = if USE_FANCY_ASCII {"↑"} else {"^"}; // "down to up" move of the shortest path
-static HEX_1_SYMB: &'static str = "1"; // hexadecimal code for a possible move
-static HEX_2_SYMB: &'static str = "2"; // hexadecimal code for a possible move
-static HEX_3_SYMB: &'static str = "3"; // hexadecimal code for a possible move
-static HEX_4_SYMB: &'static str = "4"; // hexadecimal code for a possible move
-static HEX_5_SYMB: &'static str = "5"; // hexadecimal code for a possible move
-static HEX_6_SYMB: &'static str = "6"; // hexadecimal code for a possible move
-static HEX_7_SYMB: &'static str = "7"; // hexadecimal code for a possible move
-static HEX_8_SYMB: &'static str = "8"; // hexadecimal code for a possible move
-static HEX_9_SYMB: &'static str = "9"; // hexadecimal code for a possible move
-static HEX_A_SYMB: &'static str = "A"; // hexadecimal code for a possible move
-static HEX_B_SYMB: &'static str = "B"; // hexadecimal code for a possible move
-static HEX_C_SYMB: &'static str = "C"; // hexadecimal code for a possible move
-static HEX_D_SYMB: &'static str = "D"; // hexadecimal code for a possible move
-static HEX_E_SYMB: &'static str = "E"; // hexadecimal code for a possible move
-static HEX_F_SYMB: &'static str = "F"; // hexadecimal code for a possible move
-
-// The purpose using these integer codes is that they can be stored in an array, and link to the corresponding string
-static WAL_CODE: u8 = 100;      // wall
-static NOD_CODE: u8 = 101;      // generic node
-static OPN_CODE: u8 = 102;      // opening
-static ALV_CODE: u8 = 103;      // node in the alive region
-static NAR_CODE: u8 = 104;      // node in the narrow band
-static FAR_CODE: u8 = 105;      // node in the far away region
-static PATH_L2R_CODE: u8 = 200; // node corresponding to a "left to right" move of the shortest path
-static PATH_R2L_CODE: u8 = 201; // node corresponding to a "right to left" move of the shortest path
-static PATH_U2D_CODE: u8 = 202; // node corresponding to an "up to down" move of the shortest path
-static PATH_D2U_CODE: u8 = 203; // node corresponding to a "down to up" move of the shortest path
-static HEX_1_CODE: u8 = 1;      // hexadecimal code for a possible move
-static HEX_2_CODE: u8 = 2;      // hexadecimal code for a possible move
-static HEX_3_CODE: u8 = 3;      // hexadecimal code for a possible move
-static HEX_4_CODE: u8 = 4;      // hexadecimal code for a possible move
-static HEX_5_CODE: u8 = 5;      // hexadecimal code for a possible move
-static HEX_6_CODE: u8 = 6;      // hexadecimal code for a possible move
-static HEX_7_CODE: u8 = 7;      // hexadecimal code for a possible move
-static HEX_8_CODE: u8 = 8;      // hexadecimal code for a possible move
-static HEX_9_CODE: u8 = 9;      // hexadecimal code for a possible move
-static HEX_A_CODE: u8 = 10;     // hexadecimal code for a possible move
-static HEX_B_CODE: u8 = 11;     // hexadecimal code for a possible move
-static HEX_C_CODE: u8 = 12;     // hexadecimal code for a possible move
-static HEX_D_CODE: u8 = 13;     // hexadecimal code for a possible move
-static HEX_E_CODE: u8 = 14;     // hexadecimal code for a possible move
-static HEX_F_CODE: u8 = 15;     // hexadecimal code for a possible move
-
-// 2D coordinates
-struct Pos {
-  x: usize,
-  y: usize
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ColorMode {
+  Always,
+  Never,
+  Auto
 }
 
 
 // ----------------------------------------------------------------
-// Generate a random "DIM"x"DIM" maze via recursive splitting
+// Generate a random "width"x"height" maze (via recursive splitting, by default)
 // Store the possible moves from each node via an unambiguous hexadecimal encoding
 // Solve the maze via front-propagation and extract the corresponding path via backtracking
 // Display the different processes and results in the console in ascii-art
 // ----------------------------------------------------------------
 fn main() {
 
-  // Create a DIM*DIM maze array
-  let mut maze = Array2::<u8>::zeros((DIM, DIM));
-  let mut maze_opposite = Array2::<u8>::zeros((DIM, DIM));
+  let args = Args::parse();
 
   // Create a pseudo-random number generator
-  let mut rng: StdRng = if USE_RANDOM_SEED {
-    SeedableRng::seed_from_u64(SEED)
-  } else {
-    StdRng::from_entropy()
+  let mut rng: StdRng = match args.seed {
+    Some(seed) => SeedableRng::seed_from_u64(seed),
+    None => StdRng::from_entropy()
   };
 
-  // Determine the maze orientation (true: horizontal; false: vertical)
-  let orientation = if MAZE_ORIENTATION == "horizontal" {
-    true
-  } else if MAZE_ORIENTATION == "vertical" {
-    false
-  } else if MAZE_ORIENTATION == "random" {
-    if rng.gen_range(0..2) > 0 {
-      true
-    } else {
-      false
-    }
-  } else {
-    panic!("Impossible orientation");
+  // Symbols to display the maze in the console
+  let theme = match (&args.theme, args.ascii) {
+    (Some(path), _) => load_theme(path).unwrap_or_else(exit_with_message),
+    (None, AsciiStyle::Plain) => Theme::plain(),
+    (None, AsciiStyle::Fancy) => Theme::fancy(),
+    (None, AsciiStyle::Box) => Theme::box_drawing()
   };
+  colored::control::set_override(use_colors(args.color));
 
-  // Random choice for the entrance
-  let pos_entrance = if orientation {
-    Pos{x: 0, y: rng.gen_range(0..DIM)} // entrance on the left-side wall
-  } else {
-    Pos{x: rng.gen_range(0..DIM), y: 0} // entrance on the top-side wall
-  };
+  // Print the maze while it is generated
+  if args.stream {
+    stream_maze(args.width.unwrap_or(args.size), args.height, &theme, &mut rng);
+    return;
+  }
 
-  // Random choice for the goal
-  let pos_goal = if orientation {
-    Pos{x: DIM-1, y: rng.gen_range(0..DIM)} // goal on the right-side wall
-  } else {
-    Pos{x: rng.gen_range(0..DIM), y: DIM-1} // goal on the bottom-side wall
+  // Create the maze, or load it from a file
+  let raster_options = RasterOptions{scale: args.scale, show_regions: args.shade_regions, ..RasterOptions::default()};
+  let mut animation = args.animation.as_ref().map(|_| Animation::new(args.frame_delay));
+  let mut maze = match &args.input {
+    Some(path) => {
+      let mut maze = load_maze(path).unwrap_or_else(exit_with_message);
+      println!(
+        "[loading] Maze ({}x{} nodes) generated with {} loaded from {}",
+        maze.width, maze.height, maze.generator, path.display());
+      place_entrance_and_goal(&args, &mut maze, &mut rng);
+      maze
+    },
+    None => generate_maze(&args, &theme, &raster_options, &mut animation, &mut rng)
   };
+  let (width, height) = (maze.width, maze.height);
 
-  // Keep track of the intermediate states to show the generation process
-  // This variable is "opposite" to the maze because in this process, maze_opposite are added instead of connections
-  for x in 0..DIM {
-    for y in 0..DIM {
-      maze_opposite[[y, x]] = HEX_F_CODE;
-      if x == 0 {
-        maze_opposite[[y, x]] -= R2L;
-      }
-      if x == DIM -1 {
-        maze_opposite[[y, x]] -= L2R;
-      }
-      if y == 0 {
-        maze_opposite[[y, x]] -= D2U;
-      }
-      if y == DIM -1 {
-        maze_opposite[[y, x]] -= U2D;
-      }
-    }
+  // Create loops in the maze
+  if args.braid > 0.0 {
+    let nb_doors = maze.braid(args.braid, &mut rng);
+    println!("[braiding] {} extra doors opened to remove {}% of the dead ends", nb_doors, 100.0*args.braid);
   }
-  if SHOW_GENERATION_PROCESS {
-    println!(
-      "[generation] iteration: 0");
-    let pos_dummy = Pos{x: DIM, y: DIM}; // Unreachable position for the entrance and goal, so they are not displayed
-    print_maze_in_ascii(&maze_opposite, &orientation, &pos_dummy, &pos_dummy, &maze_opposite);
-  }
-
-  // Create the maze: uppermost call to the recursive process (independent of the position of the entrance and goal)
-  let mut nb_iter_create: usize = 0;
-  recursive_region_splitting(&mut maze, &mut maze_opposite, 0, DIM-1, 0, DIM-1, &orientation, &mut nb_iter_create, &mut rng);
-  println!("[generation] Maze ({}x{} nodes) generated in {} iterations", DIM, DIM, nb_iter_create);
 
   // Print the naked maze in ascii
-  let symbol_code = get_codenames_for_naked_maze();
-  print_maze_in_ascii(&maze, &orientation, &pos_entrance, &pos_goal, &symbol_code);
+  maze.render(&mut stdout(), &get_codenames_for_floorplan(&maze), &theme).unwrap_or_else(exit_on_error);
 
   // Print the maze with hexa code
   println!("[encoding] Hexadecimal code defining the possible moves from each node");
-  print_maze_in_ascii(&maze, &orientation, &pos_entrance, &pos_goal, &maze);
+  maze.render(&mut stdout(), &maze.codes, &theme).unwrap_or_else(exit_on_error);
 
   // Solve the maze
-  solve_maze(&maze, &pos_entrance, &pos_goal, &orientation, &mut rng);
+  let solver: Box<dyn Solver> = match args.solver {
+    SolverStrategy::Random => Box::new(RandomFront),
+    SolverStrategy::BreadthFirst => Box::new(BreadthFirst),
+    SolverStrategy::DepthFirst => Box::new(DepthFirst),
+    SolverStrategy::Dijkstra => Box::new(Dijkstra),
+    SolverStrategy::AStar => Box::new(AStar)
+  };
+  let mut terminal_animation = TerminalAnimation::new(args.frame_rate, theme.nb_lines(height) +2);
+  let mut show_solving = |nb_iter_solve: usize, alv: &_, nar: &_, far: &_| {
+    if args.show_solving && args.animate {
+      terminal_animation.start_frame();
+      write_propagation_step(&mut stdout(), &maze, nb_iter_solve, alv, nar, far, &theme).unwrap_or_else(exit_on_error);
+      terminal_animation.end_frame();
+    } else if args.show_solving {
+      write_propagation_step(&mut stdout(), &maze, nb_iter_solve, alv, nar, far, &theme).unwrap_or_else(exit_on_error);
+    }
+    if let Some(animation) = animation.as_mut() {
+      animation.add_frame(propagation_step_to_image(&maze, alv, nar, far, &raster_options));
+    }
+  };
+  let show_solving: Option<ShowSolving> = if args.show_solving || args.animation.is_some() {
+    Some(&mut show_solving)
+  } else {
+    None
+  };
+  let solution = maze.solve(solver.as_ref(), show_solving, &mut rng).unwrap_or_else(exit_on_error);
+  terminal_animation.finish();
+  if !args.show_solving {
+    // Display only the last step of the front propagation
+    write_propagation_result(&mut stdout(), &maze, &solution, &theme).unwrap_or_else(exit_on_error);
+  } else if args.animate {
+    // The last step of the front propagation stays on screen, along with the iteration statistics
+    write_propagation_summary(&mut stdout(), &maze, &solution).unwrap_or_else(exit_on_error);
+  }
+
+  // Display the path extracted via backtracking
+  write_backtracking_result(&mut stdout(), &maze, &solution, &theme).unwrap_or_else(exit_on_error);
+
+  // Display how deep each node lies, from the entrance or from the requested node
+  if let Some(pos_origin) = get_heat_map_origin(&args, &maze) {
+    write_distance_map(&mut stdout(), &maze, &pos_origin, &theme).unwrap_or_else(exit_on_error);
+  }
+
+  // Measure the difficulty of the maze
+  if args.metrics {
+    let metrics = maze.metrics(&solution).unwrap_or_else(exit_on_error);
+    println!("[metrics] Difficulty of the maze ({}x{} nodes)", width, height);
+    println!("{}", metrics);
+  }
+
+  // Save the maze and its solution as an image
+  if let Some(path) = &args.output {
+    save_maze(path, &maze, &solution, &args).unwrap_or_else(exit_with_message);
+    println!("[output] Maze saved to {}", path.display());
+  }
+
+  // Save the generation and solving steps as an animation, ending with the shortest path
+  if let (Some(path), Some(mut animation)) = (&args.animation, animation) {
+    animation.add_frame(
+      maze.to_image(Some(&solution), &RasterOptions{show_regions: false, ..raster_options})
+        .unwrap_or_else(exit_with_message));
+    save_animation(path, &animation).unwrap_or_else(exit_with_message);
+    println!("[output] Animation ({} frames) saved to {}", animation.frames.len(), path.display());
+  }
 }
 
 
 // ----------------------------------------------------------------
-// Recursively generate the maze by splitting a region in two sub-regions...
-// ...and creating a connection between two neighbor nodes from each region
-// The implicit stop condition for this recursive process is: "wall_max == wall_min && door_max == door_min"
+// Save the frames of an animation as an animated GIF
 // ----------------------------------------------------------------
-fn recursive_region_splitting(
-  maze: &mut Array2<u8>, maze_opposite: &mut Array2<u8>, wall_min: usize, wall_max: usize, door_min: usize,
-  door_max: usize, orientation: &bool, nb_iter_create: &mut usize, rng: &mut StdRng) {
+fn save_animation(path: &Path, animation: &Animation) -> Result<(), String> {
+  let error_message = |error: &dyn std::fmt::Display| format!("cannot write `{}`: {}", path.display(), error);
+  let file = File::create(path).map_err(|error| error_message(&error))?;
+  animation.write_gif(BufWriter::new(file)).map_err(|error| error_message(&error))
+}
 
-  // Increment the number of iterations
-  *nb_iter_create += 1;
 
-  // Subdivide the room
-  if wall_max > wall_min {
+// ----------------------------------------------------------------
+// Generate a random maze with the algorithm, size and orientation given on the command line
+// The intermediate steps are displayed in the console and/or stored in the animation, if requested
+// ----------------------------------------------------------------
+fn generate_maze(
+  args: &Args, theme: &Theme, raster_options: &RasterOptions, animation: &mut Option<Animation>,
+  rng: &mut StdRng) -> Maze {
 
-    // Randomly determine the wall position
-    let wall_pos = rng.gen_range(wall_min..wall_max);
-    let wall_pos_plus_one = wall_pos +1;
+  // Determine the maze orientation (true: horizontal; false: vertical)
+  let orientation = match args.orientation {
+    Orientation::Horizontal => true,
+    Orientation::Vertical => false,
+    Orientation::Random => rng.gen_range(0..2) > 0
+  };
 
-    // Randomly determine the door position
-    let door_pos = if door_max > door_min {
-      rng.gen_range(door_min..door_max)
-    } else {
-      door_min
-    };
-
-    // Determine the code-word for the bi-directional move through the door in function of the current orientation
-    match orientation {
-      true => { // vertical separation, horizontal move
-        maze[[door_pos, wall_pos]] += L2R;
-        maze[[door_pos, wall_pos +1]] += R2L;
-        // The following is only needed to display the intermediate steps during maze generation
-        for y in door_min..door_pos {
-          maze_opposite[[y, wall_pos]] -= L2R;
-          maze_opposite[[y, wall_pos +1]] -= R2L;
-        }
-        for y in door_pos+1..door_max+1 {
-          maze_opposite[[y, wall_pos]] -= L2R;
-          maze_opposite[[y, wall_pos +1]] -= R2L;
-        }
-      },
-      false => { // horizontal separation, vertical move
-        maze[[wall_pos, door_pos]] += U2D;
-        maze[[wall_pos +1, door_pos]] += D2U;
-        // The following is only needed to display the intermediate steps during maze generation
-        for x in door_min..door_pos {
-          maze_opposite[[wall_pos, x]] -= U2D;
-          maze_opposite[[wall_pos +1, x]] -= D2U;
-        }
-        for x in door_pos+1..door_max+1 {
-          maze_opposite[[wall_pos, x]] -= U2D;
-          maze_opposite[[wall_pos +1, x]] -= D2U;
-        }
-      }
-    }
+  // Create the maze
+  let width = args.width.unwrap_or(args.size);
+  let height = args.height.unwrap_or(args.size);
+  let generator: Box<dyn MazeGenerator> = match args.generator {
+    Generator::RecursiveDivision => Box::new(RecursiveDivision),
+    Generator::RecursiveBacktracker => Box::new(RecursiveBacktracker),
+    Generator::Prim => Box::new(Prim),
+    Generator::Kruskal => Box::new(Kruskal),
+    Generator::Wilson => Box::new(Wilson),
+    Generator::AldousBroder => Box::new(AldousBroder),
+    Generator::Eller => Box::new(Eller)
+  };
 
-    // Display the intermediate steps
-    if SHOW_GENERATION_PROCESS {
+  // Regenerate the maze until it meets the constraints, the intermediate steps of each attempt not being displayed
+  if !args.constraints.is_empty() {
+    let placement = get_placement(args);
+    let (mut maze, report) = Maze::generate_with_constraints(
+      width, height, orientation, generator.as_ref(), placement.as_ref(), &args.constraints, args.max_attempts, rng)
+      .unwrap_or_else(|error| Args::command().error(ErrorKind::ValueValidation, error).exit());
+    maze.seed = args.seed;
+    println!(
+      "[generation] Maze ({}x{} nodes) generated with {} in {} iterations",
+      width, height, generator.name(), maze.nb_iter_create);
+    if placement.is_some() {
+      print_placement(&maze);
+    }
+    if report.is_met() {
+      println!("[constraints] All constraints met after {} attempts", report.nb_attempts);
+    } else {
       println!(
-        "[generation] iteration: {} | orientation: {} | wall: [{}, {}] --> {} | door: [{}, {}] --> {}",
-        nb_iter_create, orientation, wall_min, wall_max, wall_pos, door_min, door_max, door_pos);
-      let pos_dummy = Pos{x: DIM, y: DIM}; // Unreachable position for the entrance and goal, so they are not displayed
-      print_maze_in_ascii(&maze_opposite, &orientation, &pos_dummy, &pos_dummy, &maze_opposite);
+        "[constraints] Constraints NOT met after {} attempts, closest maze kept (total gap: {:.2})",
+        report.nb_attempts, report.gap);
     }
+    println!("{}", report);
+    return maze;
+  }
 
-    // Two recursive calls, on the regions in both sides of the wall
-    if door_max > door_min {
-      // Call with flipped orientation: the room is large enough to be subdivided along the other orientation
-      recursive_region_splitting(maze, maze_opposite, door_min, door_max, wall_min, wall_pos, &!orientation, nb_iter_create, rng);
-      recursive_region_splitting(maze, maze_opposite, door_min, door_max, wall_pos_plus_one, wall_max, &!orientation, nb_iter_create, rng);
-    } else if door_max == door_min {
-      // Call with same orientation: the room cannot be subdivided along the other orientation
-      recursive_region_splitting(maze, maze_opposite, wall_min, wall_pos, door_min, door_max, &orientation, nb_iter_create, rng);
-      recursive_region_splitting(maze, maze_opposite, wall_pos_plus_one, wall_max, door_min, door_max, &orientation, nb_iter_create, rng);
+  // Each intermediate step is printed on the lines of the maze, plus a title line and a blank line
+  let mut terminal_animation = TerminalAnimation::new(args.frame_rate, theme.nb_lines(height) +2);
+  let mut show_generation = |nb_iter_create: usize, codes: &_| {
+    if args.show_generation && args.animate {
+      terminal_animation.start_frame();
+      write_generation_step(&mut stdout(), codes, nb_iter_create, theme).unwrap_or_else(exit_on_error);
+      terminal_animation.end_frame();
+    } else if args.show_generation {
+      write_generation_step(&mut stdout(), codes, nb_iter_create, theme).unwrap_or_else(exit_on_error);
+    }
+    if let Some(animation) = animation.as_mut() {
+      animation.add_frame(generation_step_to_image(codes, raster_options));
     }
+  };
+  let show_generation: Option<ShowGeneration> = if args.show_generation || args.animation.is_some() {
+    Some(&mut show_generation)
+  } else {
+    None
+  };
+  let mut maze = Maze::generate(width, height, orientation, generator.as_ref(), show_generation, rng)
+    .unwrap_or_else(|error| Args::command().error(ErrorKind::ValueValidation, error).exit());
+  terminal_animation.finish();
+  maze.seed = args.seed;
+  println!(
+    "[generation] Maze ({}x{} nodes) generated with {} in {} iterations",
+    width, height, generator.name(), maze.nb_iter_create);
+  place_entrance_and_goal(args, &mut maze, rng);
+  maze
+}
+
 
-  // Recursive call on the same room with flipped orientation
-  } else if wall_max == wall_min && door_max > door_min {
-    recursive_region_splitting(maze, maze_opposite, door_min, door_max, wall_min, wall_max, &!orientation, nb_iter_create, rng);
+// ----------------------------------------------------------------
+// Strategy to place the entrance and the goal given on the command line, if other than keeping them as they are...
+// ...(namely, on two opposed sides)
+// ----------------------------------------------------------------
+fn get_placement(args: &Args) -> Option<Placement> {
+  match (args.entrance, args.goal, args.placement) {
+    (Some(entrance), Some(goal), _) => Some(Placement::Fixed{entrance, goal}),
+    (_, _, PlacementStrategy::OppositeSides) => None,
+    (_, _, PlacementStrategy::SameSide) => Some(Placement::SameSide),
+    (_, _, PlacementStrategy::Border) => Some(Placement::Border),
+    (_, _, PlacementStrategy::Interior) => Some(Placement::Interior),
+    (_, _, PlacementStrategy::MaxDistance) => Some(Placement::MaxDistance)
   }
 }
 
 
 // ----------------------------------------------------------------
-// Routine to display the maze in the console in ascii-art
+// Move the entrance and the goal with the strategy given on the command line, if any
 // ----------------------------------------------------------------
-fn print_maze_in_ascii(
-  maze: &Array2<u8>, orientation: &bool, pos_entrance: &Pos, pos_goal: &Pos, symbol_code: &Array2<u8>) {
+fn place_entrance_and_goal(args: &Args, maze: &mut Maze, rng: &mut StdRng) {
+  if let Some(placement) = get_placement(args) {
+    maze.place(&placement, rng).unwrap_or_else(|error| Args::command().error(ErrorKind::ValueValidation, error).exit());
+    print_placement(maze);
+  }
+}
 
-  for y in 0..DIM {
 
-    // Top half of the node
-    for x in 0..DIM {
-      // Check for entrance
-      let wall_or_entrance: &str = if !*orientation && x == pos_entrance.x && y == 0 {
-        ENT_SYMB
-      } else {
-        WAL_SYMB
-      };
-      print_ascii_node_top_half(maze[[y, x]], wall_or_entrance);
-    }
-    print!("{}\n", WAL_SYMB);
-
-    // Bottom half of the node
-    for x in 0..DIM {
-      // Check for entrance
-      let wall_or_entrance: &str = if *orientation && y == pos_entrance.y && x == 0 {
-        ENT_SYMB
-      } else {
-        WAL_SYMB
-      };
+// ----------------------------------------------------------------
+// Print the nodes of the entrance and the goal, along with the side of the outer wall they open on
+// ----------------------------------------------------------------
+fn print_placement(maze: &Maze) {
+  let describe = |gate: Gate| match gate.side {
+    Some(side) => format!("({},{}), opening {:?}", gate.pos.x, gate.pos.y, side).to_lowercase(),
+    None => format!("({},{}), inside the maze", gate.pos.x, gate.pos.y)
+  };
+  println!("[placement] Entrance at {} | goal at {}", describe(maze.entrance_gate()), describe(maze.goal_gate()));
+}
 
-      // This Fugly routine juggles between u8 codenames (nicely storable in a 2D array)...
-      // ...and the desired corresponding strings (seemingly impossible to store)
-      let symb: &str = if symbol_code[[y, x]] == WAL_CODE {
-        WAL_SYMB
-      } else if symbol_code[[y, x]] == OPN_CODE {
-        OPN_SYMB
-      } else if symbol_code[[y, x]] == NOD_CODE {
-        NOD_SYMB
-      } else if symbol_code[[y, x]] == ALV_CODE {
-        ALV_SYMB
-      } else if symbol_code[[y, x]] == NAR_CODE {
-        NAR_SYMB
-      } else if symbol_code[[y, x]] == FAR_CODE {
-        FAR_SYMB
-      } else if symbol_code[[y, x]] == PATH_L2R_CODE {
-        PATH_L2R_SYMB
-      } else if symbol_code[[y, x]] == PATH_R2L_CODE {
-        PATH_R2L_SYMB
-      } else if symbol_code[[y, x]] == PATH_U2D_CODE {
-        PATH_U2D_SYMB
-      } else if symbol_code[[y, x]] == PATH_D2U_CODE {
-        PATH_D2U_SYMB
-      } else if symbol_code[[y, x]] == HEX_1_CODE {
-        HEX_1_SYMB
-      } else if symbol_code[[y, x]] == HEX_2_CODE {
-        HEX_2_SYMB
-      } else if symbol_code[[y, x]] == HEX_3_CODE {
-        HEX_3_SYMB
-      } else if symbol_code[[y, x]] == HEX_4_CODE {
-        HEX_4_SYMB
-      } else if symbol_code[[y, x]] == HEX_5_CODE {
-        HEX_5_SYMB
-      } else if symbol_code[[y, x]] == HEX_6_CODE {
-        HEX_6_SYMB
-      } else if symbol_code[[y, x]] == HEX_7_CODE {
-        HEX_7_SYMB
-      } else if symbol_code[[y, x]] == HEX_8_CODE {
-        HEX_8_SYMB
-      } else if symbol_code[[y, x]] == HEX_9_CODE {
-        HEX_9_SYMB
-      } else if symbol_code[[y, x]] == HEX_A_CODE {
-        HEX_A_SYMB
-      } else if symbol_code[[y, x]] == HEX_B_CODE {
-        HEX_B_SYMB
-      } else if symbol_code[[y, x]] == HEX_C_CODE {
-        HEX_C_SYMB
-      } else if symbol_code[[y, x]] == HEX_D_CODE {
-        HEX_D_SYMB
-      } else if symbol_code[[y, x]] == HEX_E_CODE {
-        HEX_E_SYMB
-      } else if symbol_code[[y, x]] == HEX_F_CODE {
-        HEX_F_SYMB
-      } else {
-        panic!("Impossible configuration");
-      };
-      print_ascii_node_bot_half(maze[[y, x]], wall_or_entrance, symb);
 
-    }
+// ----------------------------------------------------------------
+// Load a maze from a file, whose format is given by the file extension
+// ----------------------------------------------------------------
+fn load_maze(path: &Path) -> Result<Maze, String> {
+  let content = std::fs::read_to_string(path).map_err(|error| format!("cannot read `{}`: {}", path.display(), error))?;
+  match get_extension(path).as_str() {
+    "json" => Maze::from_json(&content),
+    "txt" => Maze::from_ascii(&content),
+    "hex" => Maze::from_hex_string(&content),
+    _ => unreachable!("unsupported input format, rejected by parse_input")
+  }.map_err(|error| format!("cannot load `{}`: {}", path.display(), error))
+}
 
-    // Check for goal
-    if *orientation && y == pos_goal.y {
-      print!("{}\n", GOA_SYMB.red());
-    } else {
-      print!("{}\n", WAL_SYMB);
-    }
-  }
 
-  // Check for goal
-  for x in 0..DIM {
-    if !*orientation && x == pos_goal.x {
-      print!("{}{}", WAL_SYMB, GOA_SYMB.red());
-    } else {
-      print!("{}{}", WAL_SYMB, WAL_SYMB);
-    }
-  }
-  print!("{}\n\n", WAL_SYMB);
+// ----------------------------------------------------------------
+// Load the symbols used to print the maze from a TOML file
+// ----------------------------------------------------------------
+fn load_theme(path: &Path) -> Result<Theme, String> {
+  let content = std::fs::read_to_string(path).map_err(|error| format!("cannot read `{}`: {}", path.display(), error))?;
+  Theme::from_toml(&content).map_err(|error| format!("cannot load `{}`: {}", path.display(), error))
 }
 
 
 // ----------------------------------------------------------------
-// Routine to print the top-part of a given node
+// Save the maze and its solution as an image, whose format is given by the file extension
 // ----------------------------------------------------------------
-fn print_ascii_node_top_half(code: u8, wall_or_entrance: &str) {
+fn save_maze(path: &Path, maze: &Maze, solution: &Solution, args: &Args) -> Result<(), String> {
+  let error_message = |error: &dyn std::fmt::Display| format!("cannot write `{}`: {}", path.display(), error);
+  match get_extension(path).as_str() {
+    "svg" => {
+      let options = SvgOptions{
+        cell_size: args.cell_size,
+        stroke_width: args.stroke_width,
+        show_regions: args.shade_regions,
+        distance_origin: get_heat_map_origin(args, maze),
+        ..SvgOptions::default()
+      };
+      let svg = maze.to_svg(Some(solution), &options).map_err(|error| error_message(&error))?;
+      std::fs::write(path, svg).map_err(|error| error_message(&error))
+    },
+    "png" => {
+      let options = RasterOptions{
+        scale: args.scale,
+        show_regions: args.shade_regions,
+        distance_origin: get_heat_map_origin(args, maze),
+        ..RasterOptions::default()
+      };
+      let image = maze.to_image(Some(solution), &options).map_err(|error| error_message(&error))?;
+      let file = File::create(path).map_err(|error| error_message(&error))?;
+      image.write_png(BufWriter::new(file)).map_err(|error| error_message(&error))
+    },
+    "json" => std::fs::write(path, maze.to_json() + "\n").map_err(|error| error_message(&error)),
+    "hex" => std::fs::write(path, maze.to_hex_string()).map_err(|error| error_message(&error)),
+    _ => unreachable!("unsupported output format, rejected by parse_output")
+  }
+}
 
-  // Glorious hack to print symbols in a semantically-specific color
-  // Nodes with hexa code (1, 2, 3, 4, 5, 6, 7) can possibly have their top side facing a wall...
-  // ...except when in contact with the entrance
-  let wall_or_entrance_color = if wall_or_entrance == ENT_SYMB {
-    wall_or_entrance.red()
-  } else {
-    wall_or_entrance.normal()
-  };
 
-  if code == HEX_1_CODE {
-    print!("{}{}", WAL_SYMB, wall_or_entrance_color);
-  } else if code == HEX_2_CODE {
-    print!("{}{}", WAL_SYMB, wall_or_entrance_color);
-  } else if code == HEX_3_CODE {
-    print!("{}{}", WAL_SYMB, wall_or_entrance_color);
-  } else if code == HEX_4_CODE {
-    print!("{}{}", WAL_SYMB, wall_or_entrance_color);
-  } else if code == HEX_5_CODE {
-    print!("{}{}", WAL_SYMB, wall_or_entrance_color);
-  } else if code == HEX_6_CODE {
-    print!("{}{}", WAL_SYMB, wall_or_entrance_color);
-  } else if code == HEX_7_CODE {
-    print!("{}{}", WAL_SYMB, wall_or_entrance_color);
-  } else if code == HEX_8_CODE {
-    print!("{}{}", WAL_SYMB, OPN_SYMB);
-  } else if code == HEX_9_CODE {
-    print!("{}{}", WAL_SYMB, OPN_SYMB);
-  } else if code == HEX_A_CODE {
-    print!("{}{}", WAL_SYMB, OPN_SYMB);
-  } else if code == HEX_B_CODE {
-    print!("{}{}", WAL_SYMB, OPN_SYMB);
-  } else if code == HEX_C_CODE {
-    print!("{}{}", WAL_SYMB, OPN_SYMB);
-  } else if code == HEX_D_CODE {
-    print!("{}{}", WAL_SYMB, OPN_SYMB);
-  } else if code == HEX_E_CODE {
-    print!("{}{}", WAL_SYMB, OPN_SYMB);
-  } else if code == HEX_F_CODE {
-    print!("{}{}", WAL_SYMB, OPN_SYMB);
-  } else {
-    panic!("Impossible code: {}", code);
-  }
+// ----------------------------------------------------------------
+// Node from which the distances of the heat map are measured, if requested: the entrance, unless a node is given
+// ----------------------------------------------------------------
+fn get_heat_map_origin(args: &Args, maze: &Maze) -> Option<Pos> {
+  args.heat_map.map(|pos_origin| pos_origin.unwrap_or(maze.pos_entrance))
 }
 
 
 // ----------------------------------------------------------------
-// Routine to print the bottom part of a given node
+// Print a vertical maze row by row while it is generated via Eller's algorithm
+// Only the current row is stored, so the height of the maze can be arbitrarily large (or even unbounded)
 // ----------------------------------------------------------------
-fn print_ascii_node_bot_half(code: u8, wall_or_entrance: &str, symb: &str) {
+fn stream_maze(width: usize, height: Option<usize>, theme: &Theme, rng: &mut StdRng) {
 
-  // Glorious hack to print symbols in a semantically-specific color
-  // Nodes with hexa code (1, 4, 5, 8, 9, C, D) can possibly have their left side facing a wall...
-  // ...except when in contact with the entrance
-  let wall_or_entrance_color = if wall_or_entrance == ENT_SYMB {
-    wall_or_entrance.red()
-  } else {
-    wall_or_entrance.normal()
-  };
+  // A bounded maze needs at least two nodes, as any generated maze (an unbounded one has infinitely many)
+  if let Some(height) = height {
+    check_size(width, height).unwrap_or_else(exit_on_error);
+  }
 
-  // Glorious hack to print symbols in a semantically-specific color
-  let colored_symb = if symb == NOD_SYMB {
-    symb.blue()
-  } else if symb == ALV_SYMB {
-    symb.yellow()
-  } else if symb == NAR_SYMB {
-    symb.magenta()
-  } else if symb == FAR_SYMB {
-    symb.cyan()
-  } else if symb == PATH_L2R_SYMB || symb == PATH_R2L_SYMB || symb == PATH_U2D_SYMB || symb == PATH_D2U_SYMB {
-    symb.red()
-  } else if symb == HEX_1_SYMB || symb == HEX_2_SYMB || symb == HEX_3_SYMB || symb == HEX_4_SYMB ||
-    symb == HEX_5_SYMB || symb == HEX_6_SYMB || symb == HEX_7_SYMB || symb == HEX_8_SYMB ||
-    symb == HEX_9_SYMB || symb == HEX_A_SYMB || symb == HEX_B_SYMB || symb == HEX_C_SYMB ||
-    symb == HEX_D_SYMB || symb == HEX_E_SYMB || symb == HEX_F_SYMB {
-    symb.green()
-  } else {
-    panic!("Impossible symbol: {}", symb);
+  // Random choice for the entrance (top-side wall) and the goal (bottom-side wall, if any)
+  let entrance = Gate{pos: Pos{x: rng.gen_range(0..width), y: 0}, side: Some(Direction::Up)};
+  let goal = match height {
+    Some(height) => Gate{pos: Pos{x: rng.gen_range(0..width), y: height -1}, side: Some(Direction::Down)},
+    None => Gate::hidden() // The goal is not displayed
   };
 
-  if code == HEX_1_CODE {
-    print!("{}{}", wall_or_entrance_color, colored_symb);
-  } else if code == HEX_2_CODE {
-    print!("{}{}", OPN_SYMB, colored_symb);
-  } else if code == HEX_3_CODE {
-    print!("{}{}", OPN_SYMB, colored_symb);
-  } else if code == HEX_4_CODE {
-    print!("{}{}", wall_or_entrance_color, colored_symb);
-  } else if code == HEX_5_CODE {
-    print!("{}{}", wall_or_entrance_color, colored_symb);
-  } else if code == HEX_6_CODE {
-    print!("{}{}", OPN_SYMB, colored_symb);
-  } else if code == HEX_7_CODE {
-    print!("{}{}", OPN_SYMB, colored_symb);
-  } else if code == HEX_8_CODE {
-    print!("{}{}", wall_or_entrance_color, colored_symb);
-  } else if code == HEX_9_CODE {
-    print!("{}{}", wall_or_entrance_color, colored_symb);
-  } else if code == HEX_A_CODE {
-    print!("{}{}", OPN_SYMB, colored_symb);
-  } else if code == HEX_B_CODE {
-    print!("{}{}", OPN_SYMB, colored_symb);
-  } else if code == HEX_C_CODE {
-    print!("{}{}", wall_or_entrance_color, colored_symb);
-  } else if code == HEX_D_CODE {
-    print!("{}{}", wall_or_entrance_color, colored_symb);
-  } else if code == HEX_E_CODE {
-    print!("{}{}", OPN_SYMB, colored_symb);
-  } else if code == HEX_F_CODE {
-    print!("{}{}", OPN_SYMB, colored_symb);
-  } else {
-    panic!("Impossible code: {}", code);
+  // With thin walls, the corners below a row also depend on the row below: each row is thus printed one row late
+  let symbol_row = Array1::<u8>::from_elem(width, NOD_CODE);
+  let mut out = stdout();
+  let mut row_above: Option<Vec<u8>> = None;
+  for (y, maze_row) in Eller::rows(width, height, rng).enumerate() {
+    if !theme.thin_walls {
+      write_maze_row_in_ascii(&mut out, ArrayView1::from(&maze_row[..]), y, &entrance, &goal, symbol_row.view(), theme)
+    } else if let Some(row_above) = row_above.as_deref() {
+      write_maze_row_in_box_drawing(
+        &mut out, ArrayView1::from(row_above), Some(ArrayView1::from(&maze_row[..])), y -1, &entrance, &goal,
+        symbol_row.view(), theme)
+    } else {
+      Ok(())
+    }.unwrap_or_else(exit_on_error);
+    row_above = Some(maze_row);
   }
+  match (theme.thin_walls, row_above, height) {
+    (true, Some(last_row), Some(height)) =>
+      write_maze_row_in_box_drawing(
+        &mut out, ArrayView1::from(&last_row[..]), None, height -1, &entrance, &goal, symbol_row.view(), theme),
+    _ => write_maze_bottom_in_ascii(&mut out, width, height.unwrap_or(usize::MAX), &entrance, &goal, theme)
+  }.unwrap_or_else(exit_on_error);
 }
 
 
 // ----------------------------------------------------------------
-// From a given code-value "maze[[pos.y, pos.x]] = a*L2R + b*R2L +c*U2D + d*D2U", s.t. {a, b, c, d} are boolean,...
-// ...retrieve the individual components {L2R, R2L, U2D, and/or D2U} and store them in the vector "possible_moves"
-// The encoding scheme consists of fifteen different values ranging from 1 to 15, describing the possible moves
-// No need to encode a "zero" symbol because this would correspond to a non-existing fully-closed unit-sized room
-// Interestingly, each symbol {L2R, R2L, U2D, D2U} appears exactly eight times in the encoding scheme
-// ----------------------------------------------------------------
-fn get_possible_moves(maze: &Array2<u8>, pos: &Pos) -> Vec<Pos> {
-  let mut possible_moves = vec![];
-  if maze[[pos.y, pos.x]] == L2R {
-    possible_moves.push(Pos{x: pos.x +1, y: pos.y});
-  } else if maze[[pos.y, pos.x]] == R2L {
-    possible_moves.push(Pos{x: pos.x -1, y: pos.y});
-  } else if maze[[pos.y, pos.x]] == R2L + L2R {
-    possible_moves.push(Pos{x: pos.x -1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x +1, y: pos.y});
-  } else if maze[[pos.y, pos.x]] == U2D {
-    possible_moves.push(Pos{x: pos.x, y: pos.y +1});
-  } else if maze[[pos.y, pos.x]] == U2D + L2R {
-    possible_moves.push(Pos{x: pos.x +1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x, y: pos.y +1});
-  } else if maze[[pos.y, pos.x]] == U2D + R2L {
-    possible_moves.push(Pos{x: pos.x -1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x, y: pos.y +1});
-  } else if maze[[pos.y, pos.x]] == U2D + R2L + L2R {
-    possible_moves.push(Pos{x: pos.x -1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x +1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x, y: pos.y +1});
-  } else if maze[[pos.y, pos.x]] == D2U {
-    possible_moves.push(Pos{x: pos.x, y: pos.y -1});
-  } else if maze[[pos.y, pos.x]] == D2U + L2R {
-    possible_moves.push(Pos{x: pos.x +1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x, y: pos.y -1});
-  } else if maze[[pos.y, pos.x]] == D2U + R2L {
-    possible_moves.push(Pos{x: pos.x -1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x, y: pos.y -1});
-  } else if maze[[pos.y, pos.x]] == D2U + R2L + L2R {
-    possible_moves.push(Pos{x: pos.x -1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x +1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x, y: pos.y -1});
-  } else if maze[[pos.y, pos.x]] == D2U + U2D {
-    possible_moves.push(Pos{x: pos.x, y: pos.y -1});
-    possible_moves.push(Pos{x: pos.x, y: pos.y +1});
-  } else if maze[[pos.y, pos.x]] == D2U + U2D + L2R {
-    possible_moves.push(Pos{x: pos.x +1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x, y: pos.y -1});
-    possible_moves.push(Pos{x: pos.x, y: pos.y +1});
-  } else if maze[[pos.y, pos.x]] == D2U + U2D + R2L{
-    possible_moves.push(Pos{x: pos.x -1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x, y: pos.y -1});
-    possible_moves.push(Pos{x: pos.x, y: pos.y +1});
-  } else if maze[[pos.y, pos.x]] == D2U + U2D + R2L + L2R {
-    possible_moves.push(Pos{x: pos.x -1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x +1, y: pos.y});
-    possible_moves.push(Pos{x: pos.x, y: pos.y -1});
-    possible_moves.push(Pos{x: pos.x, y: pos.y +1});
-  } else {
-    panic!("Impossible move: {}", maze[[pos.y, pos.x]]);
+// The symbols are printed in color unless told otherwise, or unless the colors would end up as escape codes...
+// ...in a file (output redirected) or are turned off by the user ("NO_COLOR" set to a non-empty value)
+// ----------------------------------------------------------------
+fn use_colors(color: ColorMode) -> bool {
+  match color {
+    ColorMode::Always => true,
+    ColorMode::Never => false,
+    ColorMode::Auto => std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()) && stdout().is_terminal()
   }
-  possible_moves
 }
 
 
 // ----------------------------------------------------------------
-// The possible candidate moves from a given node have first been determined based on the presence of walls and/or doors
-// Here, the possible moves are further restricted to exclude candidate moves that do not land in the "far" region
+// Exit with the message of an error raised by the library (e.g. a maze that cannot be drawn in ascii-art)
+// A closed output is the normal way to stop printing an unbounded maze (e.g. "random-maze --stream | head"):...
+// ...the process then exits quietly
 // ----------------------------------------------------------------
-fn refine_moves_based_on_far_region(mut candidate_moves: Vec<Pos>, far_region: &Array2<bool>) -> Vec<Pos> {
-  let mut idx = 0; // This index is different to the one used in the for loop so it can adapt when elements are removed
-  for _idx_bis in 0..candidate_moves.iter().len() {
-    if !far_region[[candidate_moves[idx].y, candidate_moves[idx].x]] {
-      candidate_moves.remove(idx); // Remove candidate moves that land in a region that was already explored
-    } else {
-      idx +=1;
-    }
+fn exit_on_error<T>(error: MazeError) -> T {
+  if let MazeError::Io(io::ErrorKind::BrokenPipe, _) = error {
+    std::process::exit(0);
   }
-  candidate_moves
+  exit_with_message(error)
 }
 
 
 // ----------------------------------------------------------------
-// Prepare a lookup table of "codenames" so each node of the maze is associated with their corresponding ascii symbol
-// Here, the simple floorplan of the maze is displayed, therefore each node is represented by "NOD_SYMB"
+// Exit with the message of an error that occurred while running (e.g. a file that cannot be written), without the...
+// ...usage of the command line, which is only printed for invalid arguments
 // ----------------------------------------------------------------
-fn get_codenames_for_naked_maze() -> Array2<u8> {
-  let mut codenames = Array2::<u8>::zeros((DIM, DIM));
-  for y in 0..DIM {
-    for x in 0..DIM {
-      codenames[[y, x]] = NOD_CODE;
-    }
-  }
-  codenames
+fn exit_with_message<T>(message: impl Display) -> T {
+  eprintln!("error: {}", message);
+  std::process::exit(1)
 }
 
 
 // ----------------------------------------------------------------
-// Prepare a lookup table of "codenames" so each node of the maze is associated with their corresponding ascii symbol
-// Here, the current state of the front propagation is displayed, using "ALV_SYMB", "NAR_SYMB", or "FAR_SYMB"
+// A maze needs at least one node
 // ----------------------------------------------------------------
-fn get_codenames_for_alv_nar_far_regions(
-  alv_region: &Array2<bool>, nar_region: &Array2<bool>, far_region: &Array2<bool>) -> Array2<u8> {
-  let mut codenames = Array2::<u8>::zeros((DIM, DIM));
-  for y in 0..DIM {
-    for x in 0..DIM {
-      codenames[[y, x]] = if alv_region[[y, x]] {
-        ALV_CODE
-      } else if nar_region[[y, x]] {
-        NAR_CODE
-      } else if far_region[[y, x]] {
-        FAR_CODE
-      } else {
-        panic!("Impossible alv/nar/far configuration");
-      };
-    }
+fn parse_size(arg: &str) -> Result<usize, String> {
+  let size: usize = arg.parse().map_err(|_| format!("`{}` is not a valid size", arg))?;
+  if size == 0 {
+    return Err(String::from("the maze needs at least one node along each side"));
   }
-  codenames
+  Ok(size)
 }
 
 
 // ----------------------------------------------------------------
-// Random search on the narrow band (not a depth-first search, not a breadth-first search, not a cost-first search)
+// A fraction lies between 0 and 1
 // ----------------------------------------------------------------
-fn solve_maze(maze: &Array2<u8>, pos_entrance: &Pos, pos_goal: &Pos, orientation: &bool, rng: &mut StdRng) {
-
-  let mut alv_region = Array2::<u8>::zeros((DIM, DIM)).mapv(|_| false);
-  let mut nar_region = Array2::<u8>::zeros((DIM, DIM)).mapv(|_| false);
-  let mut far_region = Array2::<u8>::zeros((DIM, DIM)).mapv(|_| true);
-  let mut vec_narrow = vec![];
-  let mut backtracking_x = Array2::<usize>::zeros((DIM, DIM));
-  let mut backtracking_y = Array2::<usize>::zeros((DIM, DIM));
-  let mut goal_has_been_reached: bool = false;
-  let mut nb_iter_solve: usize = 0;
-
-  // Place the entrance
-  vec_narrow.push(Pos{x: pos_entrance.x, y: pos_entrance.y});
-  nar_region[[pos_entrance.y, pos_entrance.x]] = true;
-  far_region[[pos_entrance.y, pos_entrance.x]] = false;
+fn parse_fraction(arg: &str) -> Result<f64, String> {
+  let fraction: f64 = arg.parse().map_err(|_| format!("`{}` is not a valid fraction", arg))?;
+  if !(0.0..=1.0).contains(&fraction) {
+    return Err(String::from("the fraction must lie between 0 and 1"));
+  }
+  Ok(fraction)
+}
 
-  // Start searching
-  while !goal_has_been_reached {
 
-    // Increase the number of steps
-    nb_iter_solve += 1;
+// ----------------------------------------------------------------
+// A node is given by its coordinates "x,y" (whether it lies within the maze is checked once the maze is known)
+// ----------------------------------------------------------------
+fn parse_node(arg: &str) -> Result<Pos, String> {
+  let error_message = || format!("`{}` is not a valid node (expected x,y)", arg);
+  let (x, y) = arg.split_once(',').ok_or_else(error_message)?;
+  let x = x.trim().parse().map_err(|_| error_message())?;
+  let y = y.trim().parse().map_err(|_| error_message())?;
+  Ok(Pos{x, y})
+}
 
-    //Select a node from the narrow region
-    let idx_cell = if nar_region[[pos_goal.y, pos_goal.x]] {
-      // Select the goal, if the goal is in the narrow region
-      vec_narrow.iter().position(|r| r.x == pos_goal.x && r.y == pos_goal.y).unwrap()
-    } else {
-      // Else, select a random node in the narrow region
-      rng.gen_range(0..vec_narrow.iter().len())
-    };
-
-    // Instanciate a new position object with the newly-selected node
-    let pos = Pos{x: vec_narrow[idx_cell].x, y: vec_narrow[idx_cell].y};
-    alv_region[[pos.y, pos.x]]= !alv_region[[pos.y, pos.x]]; // Switch from "false" to "true"
-    nar_region[[pos.y, pos.x]]= !nar_region[[pos.y, pos.x]]; // Switch from "true" to "false"
-    vec_narrow.remove(idx_cell);
-
-    // Trigger the end of the search if the goal has been reached
-    if alv_region[[pos_goal.y, pos_goal.x]] {
-      goal_has_been_reached = true;
-    }
 
-    // Update the narrow band and the far away region in function of the new alive node
-    let mut v = get_possible_moves(&maze, &pos);
-    v = refine_moves_based_on_far_region(v, &far_region);
-    let nb_possible_moves = v.iter().len();
-    if nb_possible_moves > 0 {
-      for idx in 0..nb_possible_moves {
-        if far_region[[v[idx].y, v[idx].x]] {
-          far_region[[v[idx].y, v[idx].x]] = !far_region[[v[idx].y, v[idx].x]];
-          nar_region[[v[idx].y, v[idx].x]] = !nar_region[[v[idx].y, v[idx].x]];
-          vec_narrow.push(Pos{x: v[idx].x, y: v[idx].y});
-          backtracking_x[[v[idx].y, v[idx].x]] = pos.x;
-          backtracking_y[[v[idx].y, v[idx].x]] = pos.y;
-        }
-      }
-    }
+// ----------------------------------------------------------------
+// A constraint applies to one of the metrics of the maze (see "Constraint" for the syntax)
+// ----------------------------------------------------------------
+fn parse_constraint(arg: &str) -> Result<Constraint, String> {
+  arg.parse().map_err(|error: MazeError| error.to_string())
+}
 
-    // Display the front propagation
-    if SHOW_SOLVING_PROCESS {
-      println!("[propagation] iteration: {} | alive region: {}% | narrow band: {}% | far-away region: {}%",
-      nb_iter_solve,
-      100*count_nb_of_true(&alv_region)/(DIM*DIM),
-      100*count_nb_of_true(&nar_region)/(DIM*DIM),
-      100*count_nb_of_true(&far_region)/(DIM*DIM));
-      let symbol_code = get_codenames_for_alv_nar_far_regions(&alv_region, &nar_region, &far_region);
-      print_maze_in_ascii(&maze, &orientation, &pos_entrance, &pos_goal, &symbol_code);
-    }
-  }
 
-  // Display only the last step of the front propagation
-  if !SHOW_SOLVING_PROCESS {
-    println!(
-      "[propagation] Maze solved in {} iteration ({}% of the nodes have been visited)",
-      nb_iter_solve, 100*nb_iter_solve/(DIM*DIM));
-    let symbol_code = get_codenames_for_alv_nar_far_regions(&alv_region, &nar_region, &far_region);
-    print_maze_in_ascii(&maze, &orientation, &pos_entrance, &pos_goal, &symbol_code);
+// ----------------------------------------------------------------
+// The format of the saved image is given by the file extension
+// ----------------------------------------------------------------
+fn parse_output(arg: &str) -> Result<PathBuf, String> {
+  let path = PathBuf::from(arg);
+  match get_extension(&path).as_str() {
+    "svg" | "png" | "json" | "hex" => Ok(path),
+    _ => Err(format!("`{}` has an unsupported extension (expected .svg, .png, .json, or .hex)", arg))
   }
-
-  // Extract the path via backtracking
-  conduct_backtracking(&maze, &backtracking_x, &backtracking_y, &pos_entrance, &pos_goal, &orientation);
 }
 
 
 // ----------------------------------------------------------------
-// Extract the (unique and therefore shortest) path from the entrance to the goal via backtracking
+// A frame rate is a positive number of frames per second
 // ----------------------------------------------------------------
-fn conduct_backtracking(
-  maze: &Array2<u8>, backtracking_x: &Array2<usize>, backtracking_y: &Array2<usize>, pos_entrance: &Pos, pos_goal: &Pos,
-  orientation: &bool) {
-
-  // Initialize the backtracking array with the code-word for generic nodes
-  let mut backtracking = Array2::<u8>::zeros((DIM, DIM));
-  for y in 0..DIM {
-    for x in 0..DIM {
-      backtracking[[y, x]] = NOD_CODE;
-    }
+fn parse_frame_rate(arg: &str) -> Result<f64, String> {
+  let frame_rate: f64 = arg.parse().map_err(|_| format!("`{}` is not a valid frame rate", arg))?;
+  if !(frame_rate > 0.0 && frame_rate.is_finite()) {
+    return Err(String::from("the frame rate must be a positive number of frames per second"));
   }
+  Ok(frame_rate)
+}
 
-  // Start at the goal position
-  let mut path_length: usize = 1;
-  let mut pos_current = Pos{x: pos_goal.x, y: pos_goal.y};
-  let mut shortest_path = vec![];
-  shortest_path.push(Pos{x: pos_current.x, y: pos_current.y});
-  backtracking[[pos_current.y, pos_current.x]] = if *orientation {
-    PATH_L2R_CODE
-  } else {
-    PATH_U2D_CODE
-  };
-
-  // Iteratively backtrack the path from the goal to the entrance using the connections stored during front propagation
-  let mut entrance_has_been_reached: bool = false;
-  while !entrance_has_been_reached {
 
-    // Check if entrance has been reached, otherwise continue the bactracking
-    if pos_current.x == pos_entrance.x && pos_current.y == pos_entrance.y {
-      entrance_has_been_reached = true;
-    } else {
-      // Increase the path length
-      path_length += 1;
-
-      // Retrieve the previous position
-      let pos_prev = Pos{
-        x: backtracking_x[[pos_current.y, pos_current.x]], y: backtracking_y[[pos_current.y, pos_current.x]]};
-
-      // Encode the path direction (either L2R:">", R2L:"<", U2D:"v", or D2U:"^") in the backtracking array
-      backtracking[[pos_prev.y, pos_prev.x]] = if pos_current.x == pos_prev.x +1 && pos_current.y == pos_prev.y {
-        PATH_L2R_CODE
-      } else if pos_prev.x > 0 && pos_current.x == pos_prev.x -1 && pos_current.y == pos_prev.y {
-        PATH_R2L_CODE
-      } else if pos_current.x == pos_prev.x && pos_current.y == pos_prev.y +1 {
-        PATH_U2D_CODE
-      } else if pos_prev.y > 0 && pos_current.x == pos_prev.x && pos_current.y == pos_prev.y -1 {
-        PATH_D2U_CODE
-      } else {
-        panic!("Impossible path");
-      };
+// ----------------------------------------------------------------
+// A length in the saved SVG image is a positive number of pixels
+// ----------------------------------------------------------------
+fn parse_length(arg: &str) -> Result<f64, String> {
+  let length: f64 = arg.parse().map_err(|_| format!("`{}` is not a valid length", arg))?;
+  if !(length > 0.0 && length.is_finite()) {
+    return Err(String::from("the length must be a positive number of pixels"));
+  }
+  Ok(length)
+}
 
-      // Update the current position
-      pos_current = pos_prev;
 
-      // Update the backtracking vector
-      shortest_path.push(Pos{x: pos_current.x, y: pos_current.y});
-    }
+// ----------------------------------------------------------------
+// The format of the loaded maze is given by the file extension (ascii-art being stored in text files)
+// ----------------------------------------------------------------
+fn parse_input(arg: &str) -> Result<PathBuf, String> {
+  let path = PathBuf::from(arg);
+  match get_extension(&path).as_str() {
+    "json" | "hex" | "txt" => Ok(path),
+    _ => Err(format!("`{}` has an unsupported extension (expected .json, .hex, or .txt)", arg))
   }
+}
 
-  // Print the maze with the shortest path
-  println!("[backtracking] Path length: {}", path_length);
-  print_maze_in_ascii(&maze, &orientation, &pos_entrance, &pos_goal, &backtracking);
 
-  // Reverse the order of the path so it goes from the entrance to the goal, and print the step-by-step solution
-  shortest_path.reverse();
-  print!("Path:");
-  for idx in 0..path_length {
-    if idx % 10 == 0 {
-      print!("\n");
-    }
-    print!("{}:({},{}) ", idx, shortest_path[idx].x, shortest_path[idx].y);
+// ----------------------------------------------------------------
+// Animations are saved in the GIF format
+// ----------------------------------------------------------------
+fn parse_animation(arg: &str) -> Result<PathBuf, String> {
+  let path = PathBuf::from(arg);
+  match get_extension(&path).as_str() {
+    "gif" => Ok(path),
+    _ => Err(format!("`{}` has an unsupported extension (expected .gif)", arg))
   }
-  print!("\n");
 }
 
 
 // ----------------------------------------------------------------
-// Count the number of "True" in a boolean vector
+// Lowercase extension of a file, if any
 // ----------------------------------------------------------------
-fn count_nb_of_true(alv_nar_far_array: &Array2<bool>) -> usize {
-  let mut nb_of_true = 0;
-  for x in 0..DIM {
-    for y in 0..DIM {
-      if alv_nar_far_array[[y, x]] == true {
-        nb_of_true +=1;
-      }
-    }
-  }
-  nb_of_true
+fn get_extension(path: &Path) -> String {
+  path.extension().and_then(|extension| extension.to_str()).unwrap_or("").to_lowercase()
 }
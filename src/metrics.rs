@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::{Direction, Maze, MazeError, Openings, Pos};
+
+
+// ----------------------------------------------------------------
+// Figures to compare the difficulty of mazes, whatever their size or generation algorithm
+// A corridor is a passage in-between two nodes that are either dead ends or junctions, through nodes with exactly...
+// ...two possible moves, and its length is the number of moves from one end to the other
+// The "river" factor is the fraction of the nodes with exactly two possible moves (namely, inside a corridor), a...
+// ...simpler figure than the usual "river" of maze classifications (based on the lengths of the dead ends), which...
+// ...tells how much the maze flows as well: close to 1 for long winding passages with few dead ends, and lower for...
+// ...mazes with many short dead ends and junctions
+// The branching factor counts the possible moves at each node of the shortest path, the way back aside: 1 means...
+// ...that the shortest path never offers a choice, and higher values mean more wrong turns to avoid
+// ----------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq)]
+pub struct MazeMetrics {
+  pub nb_nodes: usize,              // number of nodes ("width"x"height")
+  pub nb_dead_ends: usize,          // nodes with a single possible move
+  pub nb_junctions: usize,          // nodes with three or four possible moves
+  pub corridor_lengths: Vec<usize>, // histogram of the lengths of the corridors (number of corridors per length)
+  pub solution_length: usize,       // number of nodes along the shortest path
+  pub solution_ratio: f64,          // fraction of the nodes that lie on the shortest path
+  pub nb_turns: usize,              // changes of direction along the shortest path (through the entrance and goal)
+  pub river_factor: f64,            // fraction of the nodes with exactly two possible moves (see above)
+  pub branching_factor: f64         // average number of ways forward at each node of the shortest path (see above)
+}
+
+impl MazeMetrics {
+
+  // Number of corridors, whatever their length
+  pub fn nb_corridors(&self) -> usize {
+    self.corridor_lengths.iter().sum()
+  }
+
+
+  // Average length of the corridors (0 if there is none)
+  pub fn mean_corridor_length(&self) -> f64 {
+    let total_length: usize = self.corridor_lengths.iter().enumerate().map(|(length, count)| length*count).sum();
+    total_length as f64/self.nb_corridors().max(1) as f64
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Print the metrics as a table, the histogram of the corridor lengths being given as "length: count" pairs
+// ----------------------------------------------------------------
+impl fmt::Display for MazeMetrics {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let percentage = |count: usize| 100*count/self.nb_nodes.max(1);
+    let histogram: Vec<String> = self.corridor_lengths.iter().enumerate()
+      .filter(|(_, &count)| count > 0)
+      .map(|(length, count)| format!("{}: {}", length, count))
+      .collect();
+    let rows = [
+      ("nodes", self.nb_nodes.to_string()),
+      ("dead ends", format!("{} ({}%)", self.nb_dead_ends, percentage(self.nb_dead_ends))),
+      ("junctions", format!("{} ({}%)", self.nb_junctions, percentage(self.nb_junctions))),
+      ("corridors", format!("{} (mean length: {:.2})", self.nb_corridors(), self.mean_corridor_length())),
+      ("corridor lengths", histogram.join(", ")),
+      ("solution length", format!("{} ({:.0}% of the nodes)", self.solution_length, 100.0*self.solution_ratio)),
+      ("turns along the solution", self.nb_turns.to_string()),
+      ("river factor", format!("{:.2}", self.river_factor)),
+      ("branching factor", format!("{:.2}", self.branching_factor))];
+    let name_width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    let value_width = rows.iter().map(|(_, value)| value.len()).max().unwrap_or(0);
+    for (name, value) in rows.iter() {
+      writeln!(f, "| {:<name_width$} | {:<value_width$} |", name, value)?;
+    }
+    Ok(())
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Measure a maze along with its shortest path (ordered from the entrance to the goal, see "Solution::path")
+// ----------------------------------------------------------------
+pub fn compute_metrics(maze: &Maze, path: &[Pos]) -> Result<MazeMetrics, MazeError> {
+
+  let nb_nodes = maze.width*maze.height;
+  let openings = maze.codes.mapv(Openings::from_bits_truncate);
+  let nb_dead_ends = openings.iter().filter(|node| node.len() == 1).count();
+  let nb_junctions = openings.iter().filter(|node| node.len() >= 3).count();
+  let nb_corridor_nodes = openings.iter().filter(|node| node.len() == 2).count();
+
+  // Walk along each corridor from one of its ends, each door being crossed once
+  // Corridors with no end at all (a loop of nodes with two possible moves) are walked from any of their nodes
+  let mut corridor_lengths = Vec::new();
+  let mut crossed_doors = HashSet::new();
+  let ends = openings.indexed_iter().filter(|(_, node)| node.len() != 2);
+  let loops = openings.indexed_iter().filter(|(_, node)| node.len() == 2);
+  for ((y, x), node) in ends.chain(loops) {
+    for dir in node.iter() {
+      if let Some(length) = walk_corridor(maze, Pos{x, y}, dir, &mut crossed_doors) {
+        if corridor_lengths.len() <= length {
+          corridor_lengths.resize(length +1, 0);
+        }
+        corridor_lengths[length] += 1;
+      }
+    }
+  }
+
+  // Directions of the moves along the shortest path, from the outside to the entrance and from the goal to the...
+  // ...outside (unless they lie inside the maze, and thus open on no outer wall)
+  let (side_entrance, side_goal) = (maze.entrance_gate().side, maze.goal_gate().side);
+  let mut dirs: Vec<Direction> = side_entrance.map(Direction::opposite).into_iter().collect();
+  for step in path.windows(2) {
+    dirs.push(Direction::between(&step[0], &step[1]).ok_or(MazeError::InvalidPath{pos_a: step[0], pos_b: step[1]})?);
+  }
+  dirs.extend(side_goal);
+  let nb_turns = dirs.windows(2).filter(|pair| pair[0] != pair[1]).count();
+
+  // Ways forward at each node of the shortest path, namely all its possible moves but the way back...
+  // ...the entrance being entered from the outside (if at all), and the goal also leading to the outside (if at all)
+  let nb_ways_forward: usize = path.iter()
+    .map(|pos| {
+      let nb_moves = openings[[pos.y, pos.x]].len() + (*pos == maze.pos_goal && side_goal.is_some()) as usize;
+      nb_moves.saturating_sub((*pos != maze.pos_entrance) as usize)
+    })
+    .sum();
+
+  Ok(MazeMetrics{
+    nb_nodes,
+    nb_dead_ends,
+    nb_junctions,
+    corridor_lengths,
+    solution_length: path.len(),
+    solution_ratio: path.len() as f64/nb_nodes.max(1) as f64,
+    nb_turns,
+    river_factor: nb_corridor_nodes as f64/nb_nodes.max(1) as f64,
+    branching_factor: nb_ways_forward as f64/path.len().max(1) as f64})
+}
+
+
+// ----------------------------------------------------------------
+// Follow a corridor from a node in a given direction, until a node that is not in-between two possible moves...
+// ...(or a door that has already been crossed, for a loop) is reached, and return its length
+// Nothing is returned if the first door has already been crossed (namely, the corridor has already been measured)
+// ----------------------------------------------------------------
+fn walk_corridor(
+  maze: &Maze, pos_start: Pos, dir_start: Direction,
+  crossed_doors: &mut HashSet<(usize, usize, bool)>) -> Option<usize> {
+  let (mut pos, mut dir) = (pos_start, dir_start);
+  let mut length = 0;
+  loop {
+    let pos_next = dir.step(&pos).filter(|pos_next| pos_next.x < maze.width && pos_next.y < maze.height)?;
+    if !crossed_doors.insert(get_door(&pos, &pos_next)) {
+      return if length > 0 {Some(length)} else {None};
+    }
+    length += 1;
+    let openings = Openings::from_bits_truncate(maze.codes[[pos_next.y, pos_next.x]]);
+    if openings.len() != 2 {
+      return Some(length);
+    }
+    (pos, dir) = (pos_next, openings.iter().find(|&next_dir| next_dir != dir.opposite())?);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Door in-between two neighbor nodes, given by the top-left node and whether the door is horizontal (left/right)
+// ----------------------------------------------------------------
+fn get_door(pos_a: &Pos, pos_b: &Pos) -> (usize, usize, bool) {
+  (pos_a.x.min(pos_b.x), pos_a.y.min(pos_b.y), pos_a.y == pos_b.y)
+}
@@ -0,0 +1,183 @@
+use ndarray::Array2;
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::{Direction, MazeError, Pos};
+use crate::distance::get_distance_map;
+
+
+// Strategy to choose the nodes of the entrance and the goal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+  OppositeSides,                  // on two opposed sides, left/right (horizontal maze) or top/bottom (vertical maze)
+  SameSide,                       // both on the side of the entrance, left (horizontal maze) or top (vertical maze)
+  Border,                         // anywhere along the outer walls
+  Interior,                       // anywhere inside the maze, away from the outer walls
+  MaxDistance,                    // the two nodes the farthest apart from one another (ends of the longest path)
+  Fixed{entrance: Pos, goal: Pos} // given nodes
+}
+
+
+// ----------------------------------------------------------------
+// Node through which the maze is entered (or left), along with the side of the outer wall it opens on, if any
+// A node in a corner touches two sides: the one given by the orientation is preferred (left or top for the...
+// ...entrance, right or bottom for the goal), then the side of the entrance for the goal (so both open on the same...
+// ...side when placed there, see "Placement::SameSide"), whereas a node inside the maze opens on no side at all
+// ----------------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gate {
+  pub pos: Pos,
+  pub side: Option<Direction>
+}
+
+impl Gate {
+
+  pub fn entrance(pos: Pos, width: usize, height: usize, orientation: bool) -> Gate {
+    let sides = if orientation {
+      [Direction::Left, Direction::Up, Direction::Down, Direction::Right]
+    } else {
+      [Direction::Up, Direction::Left, Direction::Right, Direction::Down]
+    };
+    Gate{pos, side: sides.into_iter().find(|&side| is_on_side(&pos, side, width, height))}
+  }
+
+  pub fn goal(pos: Pos, width: usize, height: usize, orientation: bool) -> Gate {
+    let sides = if orientation {
+      [Direction::Right, Direction::Left, Direction::Down, Direction::Up]
+    } else {
+      [Direction::Down, Direction::Up, Direction::Right, Direction::Left]
+    };
+    Gate{pos, side: sides.into_iter().find(|&side| is_on_side(&pos, side, width, height))}
+  }
+
+
+  // Gate that is not drawn, e.g. while the maze is being generated
+  pub fn hidden() -> Gate {
+    Gate{pos: Pos{x: usize::MAX, y: usize::MAX}, side: None}
+  }
+
+
+  // Whether the gate opens on a given side of the node ("x", "y")
+  pub fn opens(&self, x: usize, y: usize, side: Direction) -> bool {
+    self.side == Some(side) && self.pos == Pos{x, y}
+  }
+
+
+  // Whether the gate is the node ("x", "y"), inside the maze (and is thus drawn in the node itself)
+  pub fn is_inside(&self, x: usize, y: usize) -> bool {
+    self.side.is_none() && self.pos == Pos{x, y}
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Choose the nodes of the entrance and the goal (two distinct nodes) with the given strategy
+// The longest path is found via a double breadth-first traversal: the node the farthest away from any node is an...
+// ...end of the longest path, and the node the farthest away from it is the other end (in a perfect maze)
+// ----------------------------------------------------------------
+pub fn place_entrance_and_goal(
+  codes: &Array2<u8>, orientation: bool, placement: &Placement, rng: &mut StdRng) -> Result<(Pos, Pos), MazeError> {
+
+  let (height, width) = codes.dim();
+  let nodes: Vec<Pos> = (0..height).flat_map(|y| (0..width).map(move |x| Pos{x, y})).collect();
+  let is_on_border = |pos: &Pos| Direction::ALL.into_iter().any(|side| is_on_side(pos, side, width, height));
+  let (side_entrance, side_goal) = if orientation {
+    (Direction::Left, Direction::Right)
+  } else {
+    (Direction::Up, Direction::Down)
+  };
+  let on_side = |side: Direction| -> Vec<Pos> {
+    nodes.iter().copied().filter(|pos| is_on_side(pos, side, width, height)).collect()
+  };
+
+  let no_room = |location: &str| MazeError::InvalidPlacement(
+    format!("a {}x{} maze has no room for the entrance and the goal {}", width, height, location));
+  match *placement {
+    Placement::OppositeSides => {
+      pick_two(&on_side(side_entrance), &on_side(side_goal), rng).ok_or_else(|| no_room("on two opposed sides"))
+    },
+    Placement::SameSide => {
+      // A single column (horizontal maze) or row (vertical maze) lies on both opposed sides, and the goal then opens...
+      // ...on the side opposite to the entrance (see "Gate::goal")
+      if orientation && width == 1 {
+        return Err(no_room("on the same side, its only column lying on both opposed sides"));
+      }
+      if !orientation && height == 1 {
+        return Err(no_room("on the same side, its only row lying on both opposed sides"));
+      }
+      pick_two(&on_side(side_entrance), &on_side(side_entrance), rng).ok_or_else(|| no_room("on the same side"))
+    },
+    Placement::Border => {
+      let border: Vec<Pos> = nodes.iter().copied().filter(is_on_border).collect();
+      pick_two(&border, &border, rng).ok_or_else(|| no_room("along the outer walls"))
+    },
+    Placement::Interior => {
+      let interior: Vec<Pos> = nodes.iter().copied().filter(|pos| !is_on_border(pos)).collect();
+      pick_two(&interior, &interior, rng).ok_or_else(|| no_room("inside the maze"))
+    },
+    Placement::MaxDistance => {
+      let pos_start = nodes.first().ok_or_else(|| no_room("apart from one another"))?;
+      let pos_entrance = get_farthest_node(codes, pos_start);
+      let pos_goal = get_farthest_node(codes, &pos_entrance);
+      if pos_goal == pos_entrance {
+        return Err(no_room("apart from one another"));
+      }
+      Ok((pos_entrance, pos_goal))
+    },
+    Placement::Fixed{entrance, goal} => {
+      if let Some(pos) = [entrance, goal].into_iter().find(|pos| pos.x >= width || pos.y >= height) {
+        return Err(MazeError::OutOfBounds{pos});
+      }
+      if entrance == goal {
+        return Err(MazeError::InvalidPlacement(
+          format!("the entrance and the goal are the same node ({},{})", entrance.x, entrance.y)));
+      }
+      Ok((entrance, goal))
+    }
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Whether a node lies along a given side of the "width"x"height" maze
+// ----------------------------------------------------------------
+fn is_on_side(pos: &Pos, side: Direction, width: usize, height: usize) -> bool {
+  if pos.x >= width || pos.y >= height {
+    return false;
+  }
+  match side {
+    Direction::Left => pos.x == 0,
+    Direction::Right => pos.x +1 == width,
+    Direction::Up => pos.y == 0,
+    Direction::Down => pos.y +1 == height
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Random choice of the entrance among some nodes, and of the goal among some other nodes (the entrance aside)
+// ----------------------------------------------------------------
+fn pick_two(nodes_entrance: &[Pos], nodes_goal: &[Pos], rng: &mut StdRng) -> Option<(Pos, Pos)> {
+  if nodes_entrance.is_empty() {
+    return None;
+  }
+  let pos_entrance = nodes_entrance[rng.gen_range(0..nodes_entrance.len())];
+  let nodes_goal: Vec<Pos> = nodes_goal.iter().copied().filter(|pos| *pos != pos_entrance).collect();
+  if nodes_goal.is_empty() {
+    return None;
+  }
+  Some((pos_entrance, nodes_goal[rng.gen_range(0..nodes_goal.len())]))
+}
+
+
+// ----------------------------------------------------------------
+// Node the farthest away from a given node (the first one, row by row, in case of a tie)
+// ----------------------------------------------------------------
+fn get_farthest_node(codes: &Array2<u8>, pos_origin: &Pos) -> Pos {
+  get_distance_map(codes, pos_origin).indexed_iter()
+    .fold((*pos_origin, 0), |(pos_farthest, distance_max), ((y, x), distance)| match distance {
+      Some(distance) if *distance > distance_max => (Pos{x, y}, *distance),
+      _ => (pos_farthest, distance_max)
+    })
+    .0
+}
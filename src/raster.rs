@@ -0,0 +1,231 @@
+use std::io::Write;
+
+use ndarray::Array2;
+
+use crate::{Direction, Gate, Maze, MazeError, Openings, Pos};
+use crate::distance::{get_distance_map, get_gradient_color, get_max_distance};
+use crate::solving::Solution;
+
+
+// Color of a pixel (red, green, blue)
+pub type Rgb = [u8; 3];
+
+// Largest number of pixels along each side of a PNG image
+pub const PNG_MAX_SIZE: usize = (1 << 31) -1;
+
+// Settings to draw the maze as a bitmap image
+#[derive(Debug, Clone)]
+pub struct RasterOptions {
+  pub scale: usize,         // number of pixels along each side of an ascii character
+  pub show_path: bool,      // color the shortest path (if a solution is given)
+  pub show_regions: bool,   // color the nodes by their alive/narrow/far state (if a solution is given)
+  pub distance_origin: Option<Pos>, // color the nodes by their distance to this node instead (heat map)
+  pub background: Rgb,      // generic nodes and openings
+  pub wall_color: Rgb,      // walls
+  pub entrance_color: Rgb,  // entrance
+  pub goal_color: Rgb,      // goal
+  pub path_color: Rgb,      // shortest path
+  pub alv_color: Rgb,       // nodes in the alive region
+  pub nar_color: Rgb,       // nodes in the narrow band
+  pub far_color: Rgb        // nodes in the far away region
+}
+
+impl Default for RasterOptions {
+
+  // Same color scheme as in the console (red entrance, goal and path; yellow, magenta and cyan regions)
+  fn default() -> RasterOptions {
+    RasterOptions{
+      scale: 10,
+      show_path: true,
+      show_regions: false,
+      distance_origin: None,
+      background: [255, 255, 255],
+      wall_color: [0, 0, 0],
+      entrance_color: [220, 0, 0],
+      goal_color: [220, 0, 0],
+      path_color: [220, 0, 0],
+      alv_color: [255, 243, 176],
+      nar_color: [247, 198, 240],
+      far_color: [201, 241, 247]}
+  }
+}
+
+// Bitmap image, storing the color of each pixel row by row
+#[derive(Debug, Clone)]
+pub struct RgbImage {
+  pub width: usize,
+  pub height: usize,
+  pub pixels: Vec<u8>
+}
+
+impl RgbImage {
+
+  // ----------------------------------------------------------------
+  // Encode the image in the Portable Network Graphics (PNG) format
+  // ----------------------------------------------------------------
+  pub fn write_png<W: Write>(&self, writer: W) -> Result<(), MazeError> {
+    self.check_size(PNG_MAX_SIZE)?;
+    let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&self.pixels)?;
+    Ok(())
+  }
+
+
+  // ----------------------------------------------------------------
+  // Check that the image fits in a format with at most "max_size" pixels along each side, before its size is cast...
+  // ...to the integer type of that format (e.g. u16 for GIF frames)
+  // ----------------------------------------------------------------
+  pub fn check_size(&self, max_size: usize) -> Result<(), MazeError> {
+    if self.width > max_size || self.height > max_size {
+      return Err(MazeError::ImageTooLarge{width: self.width, height: self.height, max_size});
+    }
+    Ok(())
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Draw the maze as a bitmap image, along with the shortest path and the alive/narrow/far regions of a solution, if any
+// The nodes can rather be colored by their distance to a given node, from blue (nearest) to red (farthest)
+// ----------------------------------------------------------------
+pub fn maze_to_image(maze: &Maze, solution: Option<&Solution>, options: &RasterOptions) -> RgbImage {
+
+  // Color of each node
+  let mut node_colors = Array2::<Rgb>::from_elem((maze.height, maze.width), options.background);
+  if let Some(pos_origin) = &options.distance_origin {
+    let distances = get_distance_map(&maze.codes, pos_origin);
+    let max_distance = get_max_distance(&distances);
+    for (color, distance) in node_colors.iter_mut().zip(distances.iter()) {
+      if let Some(distance) = distance {
+        *color = get_gradient_color(*distance, max_distance);
+      }
+    }
+  } else if let (Some(solution), true) = (solution, options.show_regions) {
+    for ((y, x), color) in node_colors.indexed_iter_mut() {
+      *color = get_region_color(
+        solution.alv_region[[y, x]], solution.nar_region[[y, x]], solution.far_region[[y, x]], options);
+    }
+  }
+  let path: &[Pos] = match (solution, options.show_path) {
+    (Some(solution), true) => &solution.path,
+    _ => &[]
+  };
+
+  rasterize_maze(&maze.codes, &maze.entrance_gate(), &maze.goal_gate(), &node_colors, path, options)
+}
+
+
+// ----------------------------------------------------------------
+// Routine to draw the maze as a bitmap image, with the exact same layout as "ascii::write_maze_in_ascii":...
+// ...each node is a block of 2x2 ascii characters (wall, top side, left side, node), and the maze is closed by...
+// ...a right-side column and a bottom-side row, each ascii character being drawn as a square of "scale"x"scale" pixels
+// ----------------------------------------------------------------
+pub fn rasterize_maze(
+  maze: &Array2<u8>, entrance: &Gate, goal: &Gate, node_colors: &Array2<Rgb>, path: &[Pos],
+  options: &RasterOptions) -> RgbImage {
+
+  let (height, width) = maze.dim();
+
+  // Color of each ascii character, walls being the default
+  let mut blocks = Array2::<Rgb>::from_elem((2*height +1, 2*width +1), options.wall_color);
+  for y in 0..height {
+    for x in 0..width {
+      let openings = Openings::from_bits_truncate(maze[[y, x]]);
+      blocks[[2*y +1, 2*x +1]] = node_colors[[y, x]];
+      if openings.is_open(Direction::Up) {
+        blocks[[2*y, 2*x +1]] = options.background;
+      }
+      if openings.is_open(Direction::Left) {
+        blocks[[2*y +1, 2*x]] = options.background;
+      }
+    }
+  }
+
+  // Shortest path, including the openings in-between two subsequent nodes
+  for pos in path.iter() {
+    blocks[[2*pos.y +1, 2*pos.x +1]] = options.path_color;
+  }
+  for step in path.windows(2) {
+    blocks[[step[0].y + step[1].y +1, step[0].x + step[1].x +1]] = options.path_color;
+  }
+
+  // Entrance and goal, in the outer walls they open on (or in their node, when they lie inside the maze)
+  for (gate, color) in [(entrance, options.entrance_color), (goal, options.goal_color)] {
+    if let Some((row, column)) = get_gate_block(gate, width, height) {
+      blocks[[row, column]] = color;
+    }
+  }
+
+  // Upscale each ascii character to a square of pixels
+  let scale = options.scale.max(1);
+  let (image_width, image_height) = ((2*width +1)*scale, (2*height +1)*scale);
+  let mut pixels = Vec::with_capacity(3*image_width*image_height);
+  for py in 0..image_height {
+    for px in 0..image_width {
+      pixels.extend_from_slice(&blocks[[py/scale, px/scale]]);
+    }
+  }
+  RgbImage{width: image_width, height: image_height, pixels}
+}
+
+
+// ----------------------------------------------------------------
+// Ascii character of a gate: its opening in the outer wall, or its node when it lies inside the maze...
+// ...or none for a hidden gate
+// ----------------------------------------------------------------
+fn get_gate_block(gate: &Gate, width: usize, height: usize) -> Option<(usize, usize)> {
+  if gate.pos.x >= width || gate.pos.y >= height {
+    return None;
+  }
+  let (row, column) = (2*gate.pos.y +1, 2*gate.pos.x +1);
+  Some(match gate.side {
+    Some(Direction::Left) => (row, column -1),
+    Some(Direction::Right) => (row, column +1),
+    Some(Direction::Up) => (row -1, column),
+    Some(Direction::Down) => (row +1, column),
+    None => (row, column)
+  })
+}
+
+
+// ----------------------------------------------------------------
+// Draw an intermediate step of the maze generation as a bitmap image, without the entrance and goal
+// Nodes that have not been connected yet are drawn as fully-closed rooms
+// ----------------------------------------------------------------
+pub fn generation_step_to_image(maze: &Array2<u8>, options: &RasterOptions) -> RgbImage {
+  let (height, width) = maze.dim();
+  let node_colors = Array2::<Rgb>::from_elem((height, width), options.background);
+  rasterize_maze(maze, &Gate::hidden(), &Gate::hidden(), &node_colors, &[], options)
+}
+
+
+// ----------------------------------------------------------------
+// Draw an intermediate step of the front propagation as a bitmap image, each node being colored by its state
+// ----------------------------------------------------------------
+pub fn propagation_step_to_image(
+  maze: &Maze, alv_region: &Array2<bool>, nar_region: &Array2<bool>, far_region: &Array2<bool>,
+  options: &RasterOptions) -> RgbImage {
+  let mut node_colors = Array2::<Rgb>::from_elem((maze.height, maze.width), options.background);
+  for ((y, x), color) in node_colors.indexed_iter_mut() {
+    *color = get_region_color(alv_region[[y, x]], nar_region[[y, x]], far_region[[y, x]], options);
+  }
+  rasterize_maze(&maze.codes, &maze.entrance_gate(), &maze.goal_gate(), &node_colors, &[], options)
+}
+
+
+// ----------------------------------------------------------------
+// Color of a node, in function of its alive/narrow/far state
+// ----------------------------------------------------------------
+fn get_region_color(is_alv: bool, is_nar: bool, is_far: bool, options: &RasterOptions) -> Rgb {
+  if is_alv {
+    options.alv_color
+  } else if is_nar {
+    options.nar_color
+  } else if is_far {
+    options.far_color
+  } else {
+    options.background
+  }
+}
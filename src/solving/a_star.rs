@@ -0,0 +1,32 @@
+use ndarray::Array2;
+use rand::rngs::StdRng;
+
+use crate::Pos;
+use super::Solver;
+
+
+// A* algorithm: the node of the narrow band that minimizes its distance to the entrance...
+// ...plus its Manhattan distance to the goal (an optimistic estimate of the remaining path) becomes alive first
+// Ties are broken in favor of the node that is the closest to the goal
+pub struct AStar;
+
+impl Solver for AStar {
+
+  fn name(&self) -> &'static str {
+    "a-star"
+  }
+
+  fn select_node(&self, vec_narrow: &[Pos], distance: &Array2<usize>, pos_goal: &Pos, _rng: &mut StdRng) -> usize {
+    let mut idx_best = 0;
+    let mut cost_best = (usize::MAX, usize::MAX);
+    for (idx, pos) in vec_narrow.iter().enumerate() {
+      let heuristic = pos.x.abs_diff(pos_goal.x) + pos.y.abs_diff(pos_goal.y);
+      let cost = (distance[[pos.y, pos.x]] + heuristic, heuristic);
+      if cost < cost_best {
+        idx_best = idx;
+        cost_best = cost;
+      }
+    }
+    idx_best
+  }
+}
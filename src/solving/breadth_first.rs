@@ -0,0 +1,20 @@
+use ndarray::Array2;
+use rand::rngs::StdRng;
+
+use crate::Pos;
+use super::Solver;
+
+
+// Breadth-first search: the node that has been in the narrow band for the longest time becomes alive first
+pub struct BreadthFirst;
+
+impl Solver for BreadthFirst {
+
+  fn name(&self) -> &'static str {
+    "breadth-first"
+  }
+
+  fn select_node(&self, _vec_narrow: &[Pos], _distance: &Array2<usize>, _pos_goal: &Pos, _rng: &mut StdRng) -> usize {
+    0
+  }
+}
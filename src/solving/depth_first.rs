@@ -0,0 +1,20 @@
+use ndarray::Array2;
+use rand::rngs::StdRng;
+
+use crate::Pos;
+use super::Solver;
+
+
+// Depth-first search: the node that joined the narrow band last becomes alive first
+pub struct DepthFirst;
+
+impl Solver for DepthFirst {
+
+  fn name(&self) -> &'static str {
+    "depth-first"
+  }
+
+  fn select_node(&self, vec_narrow: &[Pos], _distance: &Array2<usize>, _pos_goal: &Pos, _rng: &mut StdRng) -> usize {
+    vec_narrow.len() -1
+  }
+}
@@ -0,0 +1,27 @@
+use ndarray::Array2;
+use rand::rngs::StdRng;
+
+use crate::Pos;
+use super::Solver;
+
+
+// Dijkstra's algorithm: the node of the narrow band that is the closest to the entrance becomes alive first
+// The cost of each maze node is 1, so ties are frequent: they are broken in favor of the oldest node of the narrow band
+pub struct Dijkstra;
+
+impl Solver for Dijkstra {
+
+  fn name(&self) -> &'static str {
+    "dijkstra"
+  }
+
+  fn select_node(&self, vec_narrow: &[Pos], distance: &Array2<usize>, _pos_goal: &Pos, _rng: &mut StdRng) -> usize {
+    let mut idx_best = 0;
+    for (idx, pos) in vec_narrow.iter().enumerate() {
+      if distance[[pos.y, pos.x]] < distance[[vec_narrow[idx_best].y, vec_narrow[idx_best].x]] {
+        idx_best = idx;
+      }
+    }
+    idx_best
+  }
+}
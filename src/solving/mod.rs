@@ -0,0 +1,226 @@
+use ndarray::Array2;
+use rand::rngs::StdRng;
+
+use crate::{Maze, MazeError, Openings, Pos};
+
+mod a_star;
+mod breadth_first;
+mod depth_first;
+mod dijkstra;
+mod random_front;
+
+pub use a_star::AStar;
+pub use breadth_first::BreadthFirst;
+pub use depth_first::DepthFirst;
+pub use dijkstra::Dijkstra;
+pub use random_front::RandomFront;
+
+
+// ----------------------------------------------------------------
+// Outcome of the front propagation and backtracking
+// ----------------------------------------------------------------
+#[derive(Debug, Clone)]
+pub struct Solution {
+  pub solver: &'static str,          // name of the search strategy
+  pub path: Vec<Pos>,                // shortest path, ordered from the entrance to the goal (both included)
+  pub path_length: usize,            // number of nodes along the path
+  pub nb_iter_solve: usize,          // number of iterations of the front propagation
+  pub alv_region: Array2<bool>,      // alive region at termination
+  pub nar_region: Array2<bool>,      // narrow band at termination
+  pub far_region: Array2<bool>,      // far away region at termination
+  pub backtracking_x: Array2<usize>, // ascendance ledger: x-coordinate of the previous node of each reached node
+  pub backtracking_y: Array2<usize>  // ascendance ledger: y-coordinate of the previous node of each reached node
+}
+
+
+// Callback receiving the state of the front propagation at each iteration: "(nb_iter_solve, alv, nar, far)"
+pub type ShowSolving<'a> = &'a mut dyn FnMut(usize, &Array2<bool>, &Array2<bool>, &Array2<bool>);
+
+
+// ----------------------------------------------------------------
+// Common interface of the search strategies used by the front propagation
+// All strategies share the same alive/narrow/far bookkeeping and backtracking (see "solve_maze")...
+// ...and only differ in the order in which the nodes of the narrow band become alive
+// ----------------------------------------------------------------
+pub trait Solver {
+
+  // Name of the strategy, as used on the command line
+  fn name(&self) -> &'static str;
+
+  // Select the node of the narrow band that becomes alive next, and return its index in "vec_narrow"
+  // Nodes are pushed at the end of "vec_narrow" in the order they join the narrow band
+  // "distance" holds the (currently known) distance of each reached node to the entrance
+  fn select_node(&self, vec_narrow: &[Pos], distance: &Array2<usize>, pos_goal: &Pos, rng: &mut StdRng) -> usize;
+}
+
+
+// ----------------------------------------------------------------
+// From a given code-value "maze[[pos.y, pos.x]] = a*L2R + b*R2L +c*U2D + d*D2U", s.t. {a, b, c, d} are boolean,...
+// ...retrieve the individual components {L2R, R2L, U2D, and/or D2U} and return the corresponding neighbor nodes
+// The encoding scheme consists of fifteen different values ranging from 1 to 15, describing the possible moves
+// No need to encode a "zero" symbol because this would correspond to a non-existing fully-closed unit-sized room
+// Interestingly, each symbol {L2R, R2L, U2D, D2U} appears exactly eight times in the encoding scheme
+// ----------------------------------------------------------------
+pub fn get_possible_moves(maze: &Array2<u8>, pos: &Pos) -> Result<Vec<Pos>, MazeError> {
+  let code = maze[[pos.y, pos.x]];
+  match Openings::from_bits(code) {
+    Some(openings) if !openings.is_empty() => Ok(openings.neighbors(pos)),
+    _ => Err(MazeError::InvalidCode{pos: *pos, code})
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Front propagation on the narrow band, in the order given by the search strategy
+// In a maze with loops (see "braid_maze"), a node may be reached again via a shorter way after it became alive:...
+// ...its distance to the entrance is then corrected, and it goes back to the narrow band to propagate the correction
+// The search stops once the goal is alive and no node of the narrow band can lead to a shorter path anymore
+// Inconsistent mazes are rejected beforehand, since their moves could lead outside of the maze
+// ----------------------------------------------------------------
+pub fn solve_maze(
+  maze: &Maze, solver: &dyn Solver, mut show_solving: Option<ShowSolving>,
+  rng: &mut StdRng) -> Result<Solution, MazeError> {
+
+  maze.check_consistency()?;
+
+  let (width, height) = (maze.width, maze.height);
+  let nb_nodes = width*height;
+  let pos_entrance = &maze.pos_entrance;
+  let pos_goal = &maze.pos_goal;
+
+  let mut alv_region = Array2::<u8>::zeros((height, width)).mapv(|_| false);
+  let mut nar_region = Array2::<u8>::zeros((height, width)).mapv(|_| false);
+  let mut far_region = Array2::<u8>::zeros((height, width)).mapv(|_| true);
+  let mut vec_narrow = vec![];
+  let mut backtracking_x = Array2::<usize>::zeros((height, width));
+  let mut backtracking_y = Array2::<usize>::zeros((height, width));
+  let mut distance = Array2::<usize>::from_elem((height, width), usize::MAX);
+  let mut goal_has_been_reached: bool = false;
+  let mut nb_iter_solve: usize = 0;
+
+  // In a perfect maze, the path is unique: the search can stop as soon as the goal is alive
+  let maze_is_perfect = count_nb_of_doors(&maze.codes) +1 == nb_nodes;
+
+  // Place the entrance
+  vec_narrow.push(Pos{x: pos_entrance.x, y: pos_entrance.y});
+  nar_region[[pos_entrance.y, pos_entrance.x]] = true;
+  far_region[[pos_entrance.y, pos_entrance.x]] = false;
+  distance[[pos_entrance.y, pos_entrance.x]] = 0;
+
+  // Start searching
+  while !goal_has_been_reached {
+
+    // Increase the number of steps
+    nb_iter_solve += 1;
+    if vec_narrow.is_empty() {
+      return Err(MazeError::UnreachableGoal);
+    }
+
+    //Select a node from the narrow region
+    let idx_cell = solver.select_node(&vec_narrow, &distance, pos_goal, rng);
+
+    // Instanciate a new position object with the newly-selected node
+    let pos = Pos{x: vec_narrow[idx_cell].x, y: vec_narrow[idx_cell].y};
+    alv_region[[pos.y, pos.x]]= !alv_region[[pos.y, pos.x]]; // Switch from "false" to "true"
+    nar_region[[pos.y, pos.x]]= !nar_region[[pos.y, pos.x]]; // Switch from "true" to "false"
+    vec_narrow.remove(idx_cell);
+
+    // Update the narrow band and the far away region in function of the new alive node
+    let distance_next = distance[[pos.y, pos.x]] +1;
+    for next in get_possible_moves(&maze.codes, &pos)?.iter() {
+      if far_region[[next.y, next.x]] {
+        far_region[[next.y, next.x]] = !far_region[[next.y, next.x]];
+        nar_region[[next.y, next.x]] = !nar_region[[next.y, next.x]];
+        vec_narrow.push(Pos{x: next.x, y: next.y});
+        backtracking_x[[next.y, next.x]] = pos.x;
+        backtracking_y[[next.y, next.x]] = pos.y;
+        distance[[next.y, next.x]] = distance_next;
+      } else if distance_next < distance[[next.y, next.x]] {
+        // Shorter way to an already-reached node (only possible in a maze with loops)
+        backtracking_x[[next.y, next.x]] = pos.x;
+        backtracking_y[[next.y, next.x]] = pos.y;
+        distance[[next.y, next.x]] = distance_next;
+        if alv_region[[next.y, next.x]] {
+          alv_region[[next.y, next.x]] = false;
+          nar_region[[next.y, next.x]] = true;
+          vec_narrow.push(Pos{x: next.x, y: next.y});
+        }
+      }
+    }
+
+    // Trigger the end of the search if the goal has been reached...
+    // ...and no node of the narrow band can lead to a shorter path, even via a straight line to the goal
+    if alv_region[[pos_goal.y, pos_goal.x]] {
+      let distance_goal = distance[[pos_goal.y, pos_goal.x]];
+      goal_has_been_reached = maze_is_perfect || vec_narrow.iter().all(
+        |r| distance[[r.y, r.x]] + r.x.abs_diff(pos_goal.x) + r.y.abs_diff(pos_goal.y) >= distance_goal);
+    }
+
+    // Display the front propagation
+    if let Some(show_solving) = show_solving.as_mut() {
+      show_solving(nb_iter_solve, &alv_region, &nar_region, &far_region);
+    }
+  }
+
+  // Extract the path via backtracking
+  let path = conduct_backtracking(maze, &backtracking_x, &backtracking_y)?;
+
+  Ok(Solution{
+    solver: solver.name(), path_length: path.len(), path, nb_iter_solve, alv_region, nar_region, far_region,
+    backtracking_x, backtracking_y})
+}
+
+
+// ----------------------------------------------------------------
+// Extract the shortest path (unique in a perfect maze) from the entrance to the goal via backtracking
+// ----------------------------------------------------------------
+fn conduct_backtracking(
+  maze: &Maze, backtracking_x: &Array2<usize>, backtracking_y: &Array2<usize>) -> Result<Vec<Pos>, MazeError> {
+
+  let pos_entrance = &maze.pos_entrance;
+  let pos_goal = &maze.pos_goal;
+
+  // Start at the goal position
+  let mut pos_current = Pos{x: pos_goal.x, y: pos_goal.y};
+  let mut shortest_path = vec![];
+  shortest_path.push(Pos{x: pos_current.x, y: pos_current.y});
+
+  // Iteratively backtrack the path from the goal to the entrance using the connections stored during front propagation
+  let mut entrance_has_been_reached: bool = false;
+  while !entrance_has_been_reached {
+
+    // Check if entrance has been reached, otherwise continue the bactracking
+    if pos_current.x == pos_entrance.x && pos_current.y == pos_entrance.y {
+      entrance_has_been_reached = true;
+    } else {
+      // Retrieve the previous position, which must be a direct neighbor of the current position
+      let pos_prev = Pos{
+        x: backtracking_x[[pos_current.y, pos_current.x]], y: backtracking_y[[pos_current.y, pos_current.x]]};
+      if pos_prev.x.abs_diff(pos_current.x) + pos_prev.y.abs_diff(pos_current.y) != 1 {
+        return Err(MazeError::InvalidPath{pos_a: pos_prev, pos_b: pos_current});
+      }
+
+      // Update the current position
+      pos_current = pos_prev;
+
+      // Update the backtracking vector
+      shortest_path.push(Pos{x: pos_current.x, y: pos_current.y});
+    }
+  }
+
+  // Reverse the order of the path so it goes from the entrance to the goal
+  shortest_path.reverse();
+  Ok(shortest_path)
+}
+
+
+// ----------------------------------------------------------------
+// Count the number of doors in the maze (each door appears twice: once from each side)
+// ----------------------------------------------------------------
+fn count_nb_of_doors(maze: &Array2<u8>) -> usize {
+  let mut nb_of_moves = 0;
+  for code in maze.iter() {
+    nb_of_moves += code.count_ones() as usize;
+  }
+  nb_of_moves/2
+}
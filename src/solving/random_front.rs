@@ -0,0 +1,26 @@
+use ndarray::Array2;
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::Pos;
+use super::Solver;
+
+
+// Random search on the narrow band (not a depth-first search, not a breadth-first search, not a cost-first search)
+pub struct RandomFront;
+
+impl Solver for RandomFront {
+
+  fn name(&self) -> &'static str {
+    "random"
+  }
+
+  fn select_node(&self, vec_narrow: &[Pos], _distance: &Array2<usize>, pos_goal: &Pos, rng: &mut StdRng) -> usize {
+    match vec_narrow.iter().position(|r| r.x == pos_goal.x && r.y == pos_goal.y) {
+      // Select the goal, if the goal is in the narrow region
+      Some(idx_goal) => idx_goal,
+      // Else, select a random node in the narrow region
+      None => rng.gen_range(0..vec_narrow.len())
+    }
+  }
+}
@@ -0,0 +1,185 @@
+use std::fmt::Write;
+
+use crate::{Direction, Gate, Maze, Openings, Pos};
+use crate::distance::{get_distance_map, get_gradient_color, get_max_distance};
+use crate::solving::Solution;
+
+
+// Settings to draw the maze as a Scalable Vector Graphics (SVG) image
+#[derive(Debug, Clone)]
+pub struct SvgOptions {
+  pub cell_size: f64,         // distance between two walls, in pixels
+  pub stroke_width: f64,      // thickness of the walls, in pixels
+  pub show_path: bool,        // overlay the shortest path (if a solution is given)
+  pub show_regions: bool,     // shade the nodes by their alive/narrow/far state (if a solution is given)
+  pub distance_origin: Option<Pos>, // shade the nodes by their distance to this node instead (heat map)
+  pub background: String,     // background color
+  pub wall_color: String,     // walls
+  pub entrance_color: String, // entrance marker
+  pub goal_color: String,     // goal marker
+  pub path_color: String,     // shortest path
+  pub alv_color: String,      // nodes in the alive region
+  pub nar_color: String,      // nodes in the narrow band
+  pub far_color: String       // nodes in the far away region
+}
+
+impl Default for SvgOptions {
+
+  // Same color scheme as in the console (red entrance, goal and path; yellow, magenta and cyan regions)
+  fn default() -> SvgOptions {
+    SvgOptions{
+      cell_size: 20.0,
+      stroke_width: 2.0,
+      show_path: true,
+      show_regions: false,
+      distance_origin: None,
+      background: String::from("white"),
+      wall_color: String::from("black"),
+      entrance_color: String::from("red"),
+      goal_color: String::from("red"),
+      path_color: String::from("red"),
+      alv_color: String::from("#fff3b0"),
+      nar_color: String::from("#f7c6f0"),
+      far_color: String::from("#c9f1f7")}
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Draw the maze as an SVG image, along with the shortest path and the alive/narrow/far regions of a solution, if any
+// The nodes can rather be shaded by their distance to a given node, from blue (nearest) to red (farthest)
+// The walls are derived from the possible moves of each node, exactly as in "ascii::write_maze_in_ascii":...
+// ...each node draws its top and left walls, and the maze is closed by the right-side and bottom-side walls
+// A half-cell margin surrounds the maze, so the openings of the entrance and goal remain visible
+// ----------------------------------------------------------------
+pub fn maze_to_svg(maze: &Maze, solution: Option<&Solution>, options: &SvgOptions) -> String {
+
+  let cell = options.cell_size;
+  let margin = cell/2.0;
+  let (image_width, image_height) = (maze.width as f64*cell + 2.0*margin, maze.height as f64*cell + 2.0*margin);
+  let mut svg = String::new();
+
+  // The "write!" macro into a String cannot fail, hence the ignored results
+  let _ = writeln!(
+    svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+    image_width, image_height, image_width, image_height);
+  let _ = writeln!(svg, r#"<rect width="100%" height="100%" fill="{}"/>"#, options.background);
+
+  // Shade the nodes by their distance to a given node, from blue (nearest) to red (farthest)
+  if let Some(pos_origin) = &options.distance_origin {
+    let distances = get_distance_map(&maze.codes, pos_origin);
+    let max_distance = get_max_distance(&distances);
+    let _ = writeln!(svg, r#"<g stroke="none">"#);
+    for ((y, x), distance) in distances.indexed_iter() {
+      if let Some(distance) = distance {
+        let [red, green, blue] = get_gradient_color(*distance, max_distance);
+        let _ = writeln!(
+          svg, r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#{:02x}{:02x}{:02x}"/>"##,
+          margin + x as f64*cell, margin + y as f64*cell, cell, cell, red, green, blue);
+      }
+    }
+    let _ = writeln!(svg, "</g>");
+
+  // Shade the nodes by their alive/narrow/far state
+  } else if let (Some(solution), true) = (solution, options.show_regions) {
+    let _ = writeln!(svg, r#"<g stroke="none">"#);
+    for y in 0..maze.height {
+      for x in 0..maze.width {
+        let color = if solution.alv_region[[y, x]] {
+          &options.alv_color
+        } else if solution.nar_region[[y, x]] {
+          &options.nar_color
+        } else {
+          &options.far_color
+        };
+        let _ = writeln!(
+          svg, r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+          margin + x as f64*cell, margin + y as f64*cell, cell, cell, color);
+      }
+    }
+    let _ = writeln!(svg, "</g>");
+  }
+
+  // Draw the walls as a single path made of line segments
+  let mut walls = String::new();
+  let mut add_wall = |x0: usize, y0: usize, x1: usize, y1: usize| {
+    let _ = write!(
+      walls, "M{} {}L{} {}",
+      margin + x0 as f64*cell, margin + y0 as f64*cell, margin + x1 as f64*cell, margin + y1 as f64*cell);
+  };
+  let (entrance, goal) = (maze.entrance_gate(), maze.goal_gate());
+  let is_gate = |x: usize, y: usize, side: Direction| entrance.opens(x, y, side) || goal.opens(x, y, side);
+  for y in 0..maze.height {
+    for x in 0..maze.width {
+      let openings = Openings::from_bits_truncate(maze.codes[[y, x]]);
+
+      // Top-side wall, with an opening for the entrance or the goal on the top side of the maze
+      if !openings.is_open(Direction::Up) && !is_gate(x, y, Direction::Up) {
+        add_wall(x, y, x +1, y);
+      }
+      // Left-side wall, with an opening for the entrance or the goal on the left side of the maze
+      if !openings.is_open(Direction::Left) && !is_gate(x, y, Direction::Left) {
+        add_wall(x, y, x, y +1);
+      }
+      // Right-side wall, with an opening for the entrance or the goal on the right side of the maze
+      if x +1 == maze.width && !is_gate(x, y, Direction::Right) {
+        add_wall(x +1, y, x +1, y +1);
+      }
+      // Bottom-side wall, with an opening for the entrance or the goal on the bottom side of the maze
+      if y +1 == maze.height && !is_gate(x, y, Direction::Down) {
+        add_wall(x, y +1, x +1, y +1);
+      }
+    }
+  }
+  let _ = writeln!(
+    svg, r#"<path d="{}" stroke="{}" stroke-width="{}" stroke-linecap="square" fill="none"/>"#,
+    walls, options.wall_color, options.stroke_width);
+
+  // Overlay the shortest path, from the opening of the entrance to the opening of the goal (or from/to their node)
+  if let (Some(solution), true) = (solution, options.show_path) {
+    let (pos_opening_entrance, pos_opening_goal) = (get_gate_position(&entrance, cell), get_gate_position(&goal, cell));
+    let mut points: Vec<(f64, f64)> = entrance.side.map(|_| pos_opening_entrance).into_iter().collect();
+    points.extend(solution.path.iter().map(|pos| get_center(pos, cell)));
+    points.extend(goal.side.map(|_| pos_opening_goal));
+    let points: Vec<String> = points.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+    let _ = writeln!(
+      svg, r#"<polyline points="{}" stroke="{}" stroke-width="{}" stroke-linejoin="round" fill="none"/>"#,
+      points.join(" "), options.path_color, options.stroke_width);
+  }
+
+  // Mark the entrance and the goal in the openings of the outer walls (or in their node, inside the maze)
+  let (pos_opening_entrance, pos_opening_goal) = (get_gate_position(&entrance, cell), get_gate_position(&goal, cell));
+  let _ = writeln!(
+    svg, r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+    pos_opening_entrance.0, pos_opening_entrance.1, cell/4.0, options.entrance_color);
+  let _ = writeln!(
+    svg, r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+    pos_opening_goal.0, pos_opening_goal.1, cell/4.0, options.goal_color);
+
+  svg.push_str("</svg>\n");
+  svg
+}
+
+
+// ----------------------------------------------------------------
+// Center of a node, in pixels
+// ----------------------------------------------------------------
+fn get_center(pos: &Pos, cell: f64) -> (f64, f64) {
+  let margin = cell/2.0;
+  (margin + (pos.x as f64 +0.5)*cell, margin + (pos.y as f64 +0.5)*cell)
+}
+
+
+// ----------------------------------------------------------------
+// Position of the opening of a gate in the outer wall (or of the center of its node, inside the maze), in pixels
+// ----------------------------------------------------------------
+fn get_gate_position(gate: &Gate, cell: f64) -> (f64, f64) {
+  let (x, y) = get_center(&gate.pos, cell);
+  match gate.side {
+    Some(Direction::Left) => (x - cell/2.0, y),
+    Some(Direction::Right) => (x + cell/2.0, y),
+    Some(Direction::Up) => (x, y - cell/2.0),
+    Some(Direction::Down) => (x, y + cell/2.0),
+    None => (x, y)
+  }
+}
@@ -0,0 +1,123 @@
+use std::io::{stdin, stdout, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::cursor::MoveUp;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, Clear, ClearType};
+use crossterm::queue;
+
+
+// ----------------------------------------------------------------
+// Redraw the intermediate steps of a process in place in the console, instead of printing them one below the other
+// Each frame is expected to be printed on the same number of console lines ("nb_lines")
+// While the animation runs, the following keys are available:
+// - space: pause / resume
+// - n (or right arrow): show the next frame, while paused
+// - q (or escape): skip the remaining delays
+// When the output is not a console (e.g. redirected to a file), the frames are simply printed one below the other
+// ----------------------------------------------------------------
+#[derive(Debug, Clone)]
+pub struct TerminalAnimation {
+  frame_delay: Duration,
+  nb_lines: usize,
+  is_in_place: bool,
+  nb_frames: usize,
+  is_paused: bool,
+  is_skipping: bool
+}
+
+impl TerminalAnimation {
+
+  // The frames are not delayed at all if the frame rate (in frames per second) is not positive
+  pub fn new(frame_rate: f64, nb_lines: usize) -> TerminalAnimation {
+    TerminalAnimation{
+      frame_delay: Duration::try_from_secs_f64(1.0/frame_rate).unwrap_or(Duration::ZERO),
+      nb_lines,
+      is_in_place: stdout().is_terminal(),
+      nb_frames: 0,
+      is_paused: false,
+      is_skipping: false}
+  }
+
+
+  // ----------------------------------------------------------------
+  // Move the cursor back to the top of the previous frame (if any) and clear it, so the next frame overwrites it
+  // The cursor cannot move up by more than u16::MAX lines, in which case the top of a taller frame is left on screen
+  // ----------------------------------------------------------------
+  pub fn start_frame(&mut self) {
+    if self.is_in_place && self.nb_frames > 0 && self.nb_lines > 0 {
+      let mut stdout = stdout();
+      let nb_lines = self.nb_lines.min(u16::MAX as usize) as u16;
+      let _ = queue!(stdout, MoveUp(nb_lines), Clear(ClearType::FromCursorDown));
+    }
+    self.nb_frames += 1;
+  }
+
+
+  // ----------------------------------------------------------------
+  // Show the frame that has just been printed, for the duration of a frame or until the user steps forward
+  // Frames printed one below the other (output not in a console) are not delayed
+  // ----------------------------------------------------------------
+  pub fn end_frame(&mut self) {
+    let _ = stdout().flush();
+    if self.is_skipping || !self.is_in_place {
+      return;
+    }
+
+    // Without a keyboard (e.g. when the input is redirected), simply wait
+    if !stdin().is_terminal() {
+      std::thread::sleep(self.frame_delay);
+      return;
+    }
+
+    // The raw mode is only enabled while waiting, since it disables the carriage return on new lines
+    if terminal::enable_raw_mode().is_err() {
+      std::thread::sleep(self.frame_delay);
+      return;
+    }
+    let deadline = Instant::now() + self.frame_delay;
+    loop {
+      let timeout = if self.is_paused {
+        Duration::from_millis(100)
+      } else {
+        deadline.saturating_duration_since(Instant::now())
+      };
+      if !self.is_paused && timeout.is_zero() {
+        break;
+      }
+      if !event::poll(timeout).unwrap_or(false) {
+        continue;
+      }
+      if let Ok(Event::Key(key)) = event::read() {
+        if key.kind != KeyEventKind::Press {
+          continue;
+        }
+        match key.code {
+          // The raw mode also disables the interruption signal, which is therefore handled here
+          KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            let _ = terminal::disable_raw_mode();
+            std::process::exit(130);
+          },
+          KeyCode::Char(' ') => self.is_paused = !self.is_paused,
+          KeyCode::Char('n') | KeyCode::Right if self.is_paused => break,
+          KeyCode::Char('q') | KeyCode::Esc => {
+            self.is_skipping = true;
+            break;
+          },
+          _ => {}
+        }
+      }
+    }
+    let _ = terminal::disable_raw_mode();
+  }
+
+
+  // ----------------------------------------------------------------
+  // Leave the last frame on screen, so the next printouts (or the next animation) are displayed below it
+  // ----------------------------------------------------------------
+  pub fn finish(&mut self) {
+    self.nb_frames = 0;
+    self.is_paused = false;
+    self.is_skipping = false;
+  }
+}
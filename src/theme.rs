@@ -0,0 +1,197 @@
+use colored::{Color, ColoredString, Colorize};
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+
+use crate::MazeError;
+use crate::ascii::{
+  ALV_CODE, ENT_CODE, FAR_CODE, GOL_CODE, HEX_1_CODE, HEX_F_CODE, NAR_CODE, NOD_CODE, OPN_CODE, PATH_D2U_CODE,
+  PATH_L2R_CODE, PATH_R2L_CODE, PATH_U2D_CODE, WAL_CODE};
+
+
+// Symbol drawn in the console for a given role, along with its color (if any)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Glyph {
+  pub symbol: String,
+  #[serde(default, deserialize_with = "deserialize_color")]
+  pub color: Option<Color>
+}
+
+impl Glyph {
+
+  pub fn new(symbol: &str, color: Option<Color>) -> Glyph {
+    Glyph{symbol: String::from(symbol), color}
+  }
+
+
+  // Symbol, painted in its color
+  pub fn paint(&self) -> ColoredString {
+    self.paint_symbol(&self.symbol)
+  }
+
+
+  // Any other symbol, painted in the color of the glyph (e.g. a hexadecimal digit, or a box-drawing junction)
+  pub fn paint_symbol(&self, symbol: &str) -> ColoredString {
+    match self.color {
+      Some(color) => symbol.color(color),
+      None => symbol.normal()
+    }
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Symbols (and colors) used to draw the maze in ascii-art, one per role of the characters of the console
+// A theme can be loaded from a TOML file, with one table per role, e.g.:
+// [wall]
+// symbol = "@"
+// [goal]
+// symbol = "X"
+// color = "bright red"
+// The roles that are not given are taken from the plain theme, and "hex" holds the fifteen digits of the codes 1 to F
+// With "thin_walls = true", the walls are drawn as lines of box-drawing characters, in the color of the wall
+// ----------------------------------------------------------------
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+  pub wall: Glyph,     // wall
+  pub opening: Glyph,  // opening
+  pub entrance: Glyph, // entrance
+  pub goal: Glyph,     // goal
+  pub node: Glyph,     // generic node
+  pub alive: Glyph,    // node in the alive region
+  pub narrow: Glyph,   // node in the narrow band
+  pub far: Glyph,      // node in the far away region
+  pub path_l2r: Glyph, // "left to right" move of the shortest path
+  pub path_r2l: Glyph, // "right to left" move of the shortest path
+  pub path_u2d: Glyph, // "up to down" move of the shortest path
+  pub path_d2u: Glyph, // "down to up" move of the shortest path
+  pub hex: Glyph,      // hexadecimal codes for the possible moves, from 1 to F
+  pub thin_walls: bool // walls drawn with box-drawing characters (see "box_drawing"), instead of the wall symbol
+}
+
+impl Default for Theme {
+  fn default() -> Theme {
+    Theme::plain()
+  }
+}
+
+impl Theme {
+
+  pub fn plain() -> Theme {
+    Theme::with_symbols(["#", " ", "E", "G", ".", "a", "n", "f", ">", "<", "v", "^"])
+  }
+
+  pub fn fancy() -> Theme {
+    Theme::with_symbols(["■", " ", "◆", "♥", "○", "a", "n", "f", "→", "←", "↓", "↑"])
+  }
+
+  pub fn box_drawing() -> Theme {
+    Theme{thin_walls: true, ..Theme::with_symbols(["█", " ", "●", "★", "·", "a", "n", "f", "▸", "◂", "▾", "▴"])}
+  }
+
+  // Built-in themes, by name
+  pub fn builtins() -> [(&'static str, Theme); 3] {
+    [("plain", Theme::plain()), ("fancy", Theme::fancy()), ("box", Theme::box_drawing())]
+  }
+
+
+  // Number of console lines on which a maze of "height" rows is printed (see "box_drawing" for the thin walls)
+  pub fn nb_lines(&self, height: usize) -> usize {
+    if self.thin_walls {height +1} else {2*height +1}
+  }
+
+
+  // ----------------------------------------------------------------
+  // Symbols of the built-in themes, which share the same colors:...
+  // ...red for the entrance, the goal and the path, blue for the nodes, green for the hexadecimal codes...
+  // ...and yellow, magenta, and cyan for the alive, narrow, and far away regions
+  // ----------------------------------------------------------------
+  fn with_symbols(symbols: [&str; 12]) -> Theme {
+    let [wall, opening, entrance, goal, node, alive, narrow, far, path_l2r, path_r2l, path_u2d, path_d2u] = symbols;
+    Theme{
+      wall: Glyph::new(wall, None),
+      opening: Glyph::new(opening, None),
+      entrance: Glyph::new(entrance, Some(Color::Red)),
+      goal: Glyph::new(goal, Some(Color::Red)),
+      node: Glyph::new(node, Some(Color::Blue)),
+      alive: Glyph::new(alive, Some(Color::Yellow)),
+      narrow: Glyph::new(narrow, Some(Color::Magenta)),
+      far: Glyph::new(far, Some(Color::Cyan)),
+      path_l2r: Glyph::new(path_l2r, Some(Color::Red)),
+      path_r2l: Glyph::new(path_r2l, Some(Color::Red)),
+      path_u2d: Glyph::new(path_u2d, Some(Color::Red)),
+      path_d2u: Glyph::new(path_d2u, Some(Color::Red)),
+      hex: Glyph::new("123456789ABCDEF", Some(Color::Green)),
+      thin_walls: false
+    }
+  }
+
+
+  // ----------------------------------------------------------------
+  // Read a theme written in TOML, each symbol being a single character (so the maze keeps its layout)
+  // ----------------------------------------------------------------
+  pub fn from_toml(text: &str) -> Result<Theme, MazeError> {
+    let theme: Theme = toml::from_str(text).map_err(|error| MazeError::Parse(format!("invalid theme: {}", error)))?;
+    for (role, glyph) in theme.roles() {
+      if glyph.symbol.chars().count() != 1 {
+        return Err(MazeError::Parse(format!("the `{}` symbol must be a single character", role)));
+      }
+    }
+    if theme.hex.symbol.chars().count() != 15 {
+      return Err(MazeError::Parse(String::from("the `hex` symbol must hold fifteen digits (for the codes 1 to F)")));
+    }
+    Ok(theme)
+  }
+
+
+  // ----------------------------------------------------------------
+  // Same symbols without any color, e.g. to write the maze to a file (see "Maze::render") without escape codes
+  // ----------------------------------------------------------------
+  pub fn without_colors(&self) -> Theme {
+    let mut theme = self.clone();
+    for glyph in [
+      &mut theme.wall, &mut theme.opening, &mut theme.entrance, &mut theme.goal, &mut theme.node, &mut theme.alive,
+      &mut theme.narrow, &mut theme.far, &mut theme.path_l2r, &mut theme.path_r2l, &mut theme.path_u2d,
+      &mut theme.path_d2u, &mut theme.hex] {
+      glyph.color = None;
+    }
+    theme
+  }
+
+
+  // Single-character roles, by name
+  pub fn roles(&self) -> [(&'static str, &Glyph); 12] {
+    [
+      ("wall", &self.wall), ("opening", &self.opening), ("entrance", &self.entrance), ("goal", &self.goal),
+      ("node", &self.node), ("alive", &self.alive), ("narrow", &self.narrow), ("far", &self.far),
+      ("path_l2r", &self.path_l2r), ("path_r2l", &self.path_r2l), ("path_u2d", &self.path_u2d),
+      ("path_d2u", &self.path_d2u)]
+  }
+
+
+  // ----------------------------------------------------------------
+  // Symbol of a "codename" (see "ascii::WAL_CODE" and the following ones), painted in its color, if any
+  // ----------------------------------------------------------------
+  pub fn paint(&self, symbol_code: u8) -> Option<ColoredString> {
+    if (HEX_1_CODE..=HEX_F_CODE).contains(&symbol_code) {
+      let digit = self.hex.symbol.chars().nth((symbol_code - HEX_1_CODE) as usize)?;
+      return Some(self.hex.paint_symbol(&digit.to_string()));
+    }
+    let codenames = [
+      (WAL_CODE, &self.wall), (OPN_CODE, &self.opening), (ENT_CODE, &self.entrance), (GOL_CODE, &self.goal),
+      (NOD_CODE, &self.node), (ALV_CODE, &self.alive), (NAR_CODE, &self.narrow), (FAR_CODE, &self.far),
+      (PATH_L2R_CODE, &self.path_l2r), (PATH_R2L_CODE, &self.path_r2l), (PATH_U2D_CODE, &self.path_u2d),
+      (PATH_D2U_CODE, &self.path_d2u)];
+    codenames.iter().find(|(code, _)| *code == symbol_code).map(|(_, glyph)| glyph.paint())
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Read a color by its name (e.g. "red", "bright blue"), as understood by the "colored" crate
+// ----------------------------------------------------------------
+fn deserialize_color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Color>, D::Error> {
+  let name: String = Deserialize::deserialize(deserializer)?;
+  name.parse().map(Some).map_err(|_| D::Error::custom(format!("unknown color `{}`", name)))
+}
@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use ndarray::Array2;
+
+use crate::{Maze, Pos, L2R, R2L, U2D, D2U};
+
+
+// Inconsistency found in a maze
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+  DimensionMismatch{width: usize, height: usize, nb_columns: usize, nb_rows: usize}, // codes of another size
+  TooFewNodes{nb_nodes: usize},             // a maze needs at least two nodes
+  InvalidCode{pos: Pos, code: u8},          // code 0 (fully-closed node), or above F
+  OutOfBoundsMove{pos: Pos, code: u8},      // move through the outer walls
+  AsymmetricDoor{pos_a: Pos, pos_b: Pos},   // move from a node to its neighbor, but not the other way around
+  MisplacedEntrance{pos: Pos},              // entrance outside of the maze
+  MisplacedGoal{pos: Pos},                  // goal outside of the maze
+  DisconnectedComponents{nb_components: usize}, // nodes that cannot be reached from one another
+  Cycles{nb_extra_doors: usize}             // doors to remove for the maze to be perfect (when expected to be)
+}
+
+impl fmt::Display for ValidationError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ValidationError::DimensionMismatch{width, height, nb_columns, nb_rows} =>
+        write!(f, "the codes are {}x{}, whereas the maze is {}x{}", nb_columns, nb_rows, width, height),
+      ValidationError::TooFewNodes{nb_nodes} =>
+        write!(f, "the maze has {} node(s), whereas it needs at least two", nb_nodes),
+      ValidationError::InvalidCode{pos, code} =>
+        write!(f, "invalid code {:X} at ({},{}) (expected 1 to F)", code, pos.x, pos.y),
+      ValidationError::OutOfBoundsMove{pos, code} =>
+        write!(f, "the code {:X} at ({},{}) leads outside of the maze", code, pos.x, pos.y),
+      ValidationError::AsymmetricDoor{pos_a, pos_b} =>
+        write!(f, "the moves between ({},{}) and ({},{}) are not bi-directional", pos_a.x, pos_a.y, pos_b.x, pos_b.y),
+      ValidationError::MisplacedEntrance{pos} =>
+        write!(f, "the entrance ({},{}) is outside of the maze", pos.x, pos.y),
+      ValidationError::MisplacedGoal{pos} =>
+        write!(f, "the goal ({},{}) is outside of the maze", pos.x, pos.y),
+      ValidationError::DisconnectedComponents{nb_components} =>
+        write!(f, "the maze is split in {} disconnected components", nb_components),
+      ValidationError::Cycles{nb_extra_doors} =>
+        write!(f, "the maze is not perfect: {} door(s) would have to be removed to break its loops", nb_extra_doors)
+    }
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Check the consistency of the possible moves from each node, and report every inconsistency found
+// If "expect_perfect" holds, the maze shall also have no loop (namely, a unique path in-between any two nodes)
+// ----------------------------------------------------------------
+pub fn validate_maze(maze: &Maze, expect_perfect: bool) -> Vec<ValidationError> {
+
+  let (width, height) = (maze.width, maze.height);
+  let (nb_rows, nb_columns) = maze.codes.dim();
+  if (nb_columns, nb_rows) != (width, height) {
+    // The codes cannot be checked any further
+    return vec![ValidationError::DimensionMismatch{width, height, nb_columns, nb_rows}];
+  }
+  let mut errors = vec![];
+  if width*height < 2 {
+    errors.push(ValidationError::TooFewNodes{nb_nodes: width*height});
+  }
+
+  // Entrance and goal anywhere in the maze (see "placement::Placement"), opening on the outer walls they touch
+  let (pos_entrance, pos_goal) = (maze.pos_entrance, maze.pos_goal);
+  if pos_entrance.x >= width || pos_entrance.y >= height {
+    errors.push(ValidationError::MisplacedEntrance{pos: pos_entrance});
+  }
+  if pos_goal.x >= width || pos_goal.y >= height {
+    errors.push(ValidationError::MisplacedGoal{pos: pos_goal});
+  }
+
+  // Code of each node, and doors in-between neighbor nodes
+  for y in 0..height {
+    for x in 0..width {
+      let (pos, code) = (Pos{x, y}, maze.codes[[y, x]]);
+      if code == 0 || code > L2R + R2L + U2D + D2U {
+        errors.push(ValidationError::InvalidCode{pos, code});
+      }
+      if (x == 0 && code & R2L != 0) || (x +1 == width && code & L2R != 0) ||
+        (y == 0 && code & D2U != 0) || (y +1 == height && code & U2D != 0) {
+        errors.push(ValidationError::OutOfBoundsMove{pos, code});
+      }
+      if x +1 < width && (code & L2R != 0) != (maze.codes[[y, x +1]] & R2L != 0) {
+        errors.push(ValidationError::AsymmetricDoor{pos_a: pos, pos_b: Pos{x: x +1, y}});
+      }
+      if y +1 < height && (code & U2D != 0) != (maze.codes[[y +1, x]] & D2U != 0) {
+        errors.push(ValidationError::AsymmetricDoor{pos_a: pos, pos_b: Pos{x, y: y +1}});
+      }
+    }
+  }
+
+  // Connected components and loops, the doors being the moves allowed from either side
+  let (nb_components, nb_doors) = count_components_and_doors(&maze.codes);
+  if nb_components > 1 {
+    errors.push(ValidationError::DisconnectedComponents{nb_components});
+  }
+  // A forest of "nb_components" trees spanning all the nodes has exactly "width*height - nb_components" doors
+  let nb_extra_doors = nb_doors - (width*height - nb_components);
+  if expect_perfect && nb_extra_doors > 0 {
+    errors.push(ValidationError::Cycles{nb_extra_doors});
+  }
+
+  errors
+}
+
+
+// ----------------------------------------------------------------
+// Count the connected components of the maze (via breadth-first flood fill) and the doors in-between neighbor nodes
+// ----------------------------------------------------------------
+fn count_components_and_doors(codes: &Array2<u8>) -> (usize, usize) {
+
+  let (height, width) = codes.dim();
+  let is_door_right = |x: usize, y: usize| {
+    x +1 < width && (codes[[y, x]] & L2R != 0 || codes[[y, x +1]] & R2L != 0)
+  };
+  let is_door_down = |x: usize, y: usize| {
+    y +1 < height && (codes[[y, x]] & U2D != 0 || codes[[y +1, x]] & D2U != 0)
+  };
+
+  let mut nb_doors = 0;
+  for y in 0..height {
+    for x in 0..width {
+      nb_doors += is_door_right(x, y) as usize + is_door_down(x, y) as usize;
+    }
+  }
+
+  let mut visited = Array2::<bool>::from_elem((height, width), false);
+  let mut nb_components = 0;
+  for y_start in 0..height {
+    for x_start in 0..width {
+      if visited[[y_start, x_start]] {
+        continue;
+      }
+      nb_components += 1;
+      visited[[y_start, x_start]] = true;
+      let mut queue = VecDeque::from([Pos{x: x_start, y: y_start}]);
+      while let Some(Pos{x, y}) = queue.pop_front() {
+        let mut neighbors = vec![];
+        if is_door_right(x, y) {
+          neighbors.push(Pos{x: x +1, y});
+        }
+        if x > 0 && is_door_right(x -1, y) {
+          neighbors.push(Pos{x: x -1, y});
+        }
+        if is_door_down(x, y) {
+          neighbors.push(Pos{x, y: y +1});
+        }
+        if y > 0 && is_door_down(x, y -1) {
+          neighbors.push(Pos{x, y: y -1});
+        }
+        for neighbor in neighbors {
+          if !visited[[neighbor.y, neighbor.x]] {
+            visited[[neighbor.y, neighbor.x]] = true;
+            queue.push_back(neighbor);
+          }
+        }
+      }
+    }
+  }
+  (nb_components, nb_doors)
+}
@@ -0,0 +1,89 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use random_maze::{Maze, MazeGenerator, Placement, Theme};
+use random_maze::ascii::{
+  get_codenames_for_alv_nar_far_regions, get_codenames_for_floorplan, get_codenames_for_path, write_distance_map};
+use random_maze::generation::{Kruskal, RecursiveDivision, Wilson};
+use random_maze::solving::BreadthFirst;
+
+
+// ----------------------------------------------------------------
+// Printing a maze in ascii-art with any of the built-in themes and reading it back yields the same maze, wherever...
+// ...its entrance and goal are placed (e.g. next to one another, on the same side)
+// ----------------------------------------------------------------
+#[test]
+fn ascii_round_trip() {
+  let generators: [&dyn MazeGenerator; 3] = [&RecursiveDivision, &Kruskal, &Wilson];
+  let placements = [
+    Placement::OppositeSides, Placement::SameSide, Placement::Border, Placement::Interior, Placement::MaxDistance];
+  for seed in 0..200 {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed as u64);
+    let (width, height) = (1 + seed % 6, 2 + seed % 5);
+    let generator = generators[seed % generators.len()];
+    let mut maze = Maze::generate(width, height, seed % 2 == 0, generator, None, &mut rng).unwrap();
+    maze.braid(0.5, &mut rng);
+    if maze.place(&placements[seed % placements.len()], &mut rng).is_err() {
+      continue; // e.g. no room inside a small maze
+    }
+
+    for (name, theme) in Theme::builtins() {
+      let mut printout = Vec::new();
+      maze.render(&mut printout, &get_codenames_for_floorplan(&maze), &theme.without_colors()).unwrap();
+      let maze_read = Maze::from_ascii(&String::from_utf8(printout).unwrap()).unwrap();
+      assert_eq!(maze_read.codes, maze.codes, "{} theme on seed {}", name, seed);
+      assert_eq!(maze_read.pos_entrance, maze.pos_entrance, "{} theme on seed {}", name, seed);
+      assert_eq!(maze_read.pos_goal, maze.pos_goal, "{} theme on seed {}", name, seed);
+      assert_eq!(maze_read.entrance_gate(), maze.entrance_gate(), "{} theme on seed {}", name, seed);
+      assert_eq!(maze_read.goal_gate(), maze.goal_gate(), "{} theme on seed {}", name, seed);
+    }
+  }
+}
+
+
+// ----------------------------------------------------------------
+// With thin walls, the symbols drawn in the nodes (code-words, alive/narrow/far regions, path, heat map) leave the...
+// ...corners and the sides of the maze as in its floorplan, so no wall is hidden by an overlay
+// ----------------------------------------------------------------
+#[test]
+fn thin_walls_are_kept_under_overlays() {
+  colored::control::set_override(false);
+  let theme = Theme::box_drawing().without_colors();
+  for seed in 0..50 {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed as u64);
+    let (width, height) = (2 + seed % 7, 2 + seed % 5);
+    let mut maze = Maze::generate(width, height, seed % 2 == 0, &Kruskal, None, &mut rng).unwrap();
+    maze.braid(0.3, &mut rng);
+    let solution = maze.solve(&BreadthFirst, None, &mut rng).unwrap();
+
+    let render = |symbol_code| {
+      let mut printout = Vec::new();
+      maze.render(&mut printout, &symbol_code, &theme).unwrap();
+      String::from_utf8(printout).unwrap()
+    };
+    let floorplan = render(get_codenames_for_floorplan(&maze));
+    let mut heat_map = Vec::new();
+    write_distance_map(&mut heat_map, &maze, &maze.pos_entrance, &theme).unwrap();
+    let overlays = [
+      ("encoding", render(maze.codes.clone())),
+      ("regions", render(get_codenames_for_alv_nar_far_regions(
+        &solution.alv_region, &solution.nar_region, &solution.far_region).unwrap())),
+      ("path", render(get_codenames_for_path(&maze, &solution.path).unwrap())),
+      ("heat map", String::from_utf8(heat_map).unwrap().split_once('\n').unwrap().1.to_string())];
+
+    for (name, printout) in overlays {
+      assert_eq!(printout.lines().count(), floorplan.lines().count(), "{} on seed {}", name, seed);
+      for (line, line_floorplan) in printout.lines().zip(floorplan.lines()) {
+        assert_eq!(get_walls(line), get_walls(line_floorplan), "{} on seed {}", name, seed);
+      }
+    }
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Corners and sides of a line printed with thin walls, leaving out the cell of each node (see "box_drawing")
+// ----------------------------------------------------------------
+fn get_walls(line: &str) -> String {
+  line.chars().enumerate().filter(|(column, _)| column % 3 != 1).map(|(_, c)| c).collect()
+}
@@ -0,0 +1,104 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use random_maze::{Constraint, Maze, MazeError, MazeMetrics, Metric};
+use random_maze::distance::get_shortest_path;
+use random_maze::generation::{Kruskal, RecursiveBacktracker};
+use random_maze::metrics::compute_metrics;
+
+
+// ----------------------------------------------------------------
+// Constraints are written as "metric>=min", "metric<=max", "metric=min..max", or "metric=value"
+// ----------------------------------------------------------------
+#[test]
+fn constraints_are_parsed() {
+  let cases = [
+    ("dead-ends>=20", Constraint::at_least(Metric::DeadEnds, 20.0)),
+    (" turns <= 8 ", Constraint::at_most(Metric::Turns, 8.0)),
+    ("solution-ratio=0.4..0.6", Constraint::between(Metric::SolutionRatio, 0.4, 0.6)),
+    ("branching-factor=1.5", Constraint::between(Metric::BranchingFactor, 1.5, 1.5))];
+  for (text, constraint) in cases {
+    assert_eq!(text.parse::<Constraint>(), Ok(constraint), "`{}`", text);
+    assert_eq!(constraint.to_string().parse::<Constraint>(), Ok(constraint), "`{}`", text);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Malformed constraints are rejected: no operator, unknown metric, missing or infinite bound, or empty range
+// ----------------------------------------------------------------
+#[test]
+fn malformed_constraints_are_rejected() {
+  for text in ["", "dead-ends", "dead-ends>>3", "walls>=3", "turns>=", "turns<=abc", "turns=inf", "turns=5..3"] {
+    assert!(matches!(text.parse::<Constraint>(), Err(MazeError::Parse(_))), "`{}`", text);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// The gap to a violated bound is relative to this bound, or absolute for bounds below 1 (e.g. fractions)
+// ----------------------------------------------------------------
+#[test]
+fn gap_to_constraints() {
+  let metrics = MazeMetrics{
+    nb_nodes: 100, nb_dead_ends: 15, nb_junctions: 10, corridor_lengths: vec![0, 4, 2], solution_length: 70,
+    solution_ratio: 0.7, nb_turns: 30, river_factor: 0.5, branching_factor: 1.2};
+  let cases = [
+    (Constraint::at_least(Metric::DeadEnds, 20.0), 0.25),
+    (Constraint::at_least(Metric::DeadEnds, 15.0), 0.0),
+    (Constraint::at_most(Metric::Junctions, 8.0), 0.25),
+    (Constraint::at_most(Metric::SolutionRatio, 0.5), 0.7 - 0.5),
+    (Constraint::between(Metric::Corridors, 2.0, 4.0), 0.5),
+    (Constraint::between(Metric::Turns, 20.0, 40.0), 0.0),
+    (Constraint::between(Metric::RiverFactor, 0.6, 0.6), 0.6 - 0.5)];
+  for (constraint, gap) in cases {
+    assert_eq!(constraint.gap(&metrics), gap, "{}", constraint);
+    assert_eq!(constraint.is_met(&metrics), gap == 0.0, "{}", constraint);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// The returned maze meets the constraints when one of the attempts does, and it comes the closest to them otherwise:...
+// ...its gap is the smallest among the mazes generated by the same sequence of attempts
+// ----------------------------------------------------------------
+#[test]
+fn generated_maze_satisfies_or_minimises_the_gap() {
+  for seed in 0..10 {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed);
+    let constraints = ["dead-ends>=3".parse::<Constraint>().unwrap(), "turns=4..30".parse().unwrap()];
+    let (maze, report) = Maze::generate_with_constraints(
+      6, 6, true, &RecursiveBacktracker, None, &constraints, 50, &mut rng).unwrap();
+    assert!(report.is_met(), "seed {}", seed);
+    assert!(constraints.iter().all(|constraint| constraint.is_met(&report.metrics)), "seed {}", seed);
+    assert_eq!(measure(&maze), report.metrics, "seed {}", seed);
+  }
+
+  for seed in 0..10 {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed);
+    let constraints = ["solution-ratio>=1".parse::<Constraint>().unwrap(), "dead-ends<=2".parse().unwrap()];
+    let (maze, report) = Maze::generate_with_constraints(
+      8, 8, false, &Kruskal, None, &constraints, 20, &mut rng).unwrap();
+    assert!(!report.is_met(), "seed {}", seed);
+    assert_eq!(report.nb_attempts, 20, "seed {}", seed);
+    assert_eq!(measure(&maze), report.metrics, "seed {}", seed);
+
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed);
+    let min_gap = (0..20)
+      .map(|_| {
+        let metrics = measure(&Maze::generate(8, 8, false, &Kruskal, None, &mut rng).unwrap());
+        constraints.iter().map(|constraint| constraint.gap(&metrics)).sum::<f64>()
+      })
+      .fold(f64::INFINITY, f64::min);
+    assert_eq!(report.gap, min_gap, "seed {}", seed);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Metrics of a maze along its shortest path
+// ----------------------------------------------------------------
+fn measure(maze: &Maze) -> MazeMetrics {
+  let path = get_shortest_path(&maze.codes, &maze.pos_entrance, &maze.pos_goal).unwrap();
+  compute_metrics(maze, &path).unwrap()
+}
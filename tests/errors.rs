@@ -0,0 +1,120 @@
+use ndarray::Array2;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use random_maze::{
+  Animation, Constraint, Maze, MazeError, MazeGenerator, Placement, Pos, RasterOptions, RgbImage, ShowGeneration,
+  SvgOptions, ValidationError};
+use random_maze::generation::Kruskal;
+use random_maze::solving::BreadthFirst;
+
+
+// ----------------------------------------------------------------
+// Generator of mazes with no door in-between their rows, each row being a corridor
+// ----------------------------------------------------------------
+struct Corridors;
+
+impl MazeGenerator for Corridors {
+
+  fn name(&self) -> &'static str {
+    "corridors"
+  }
+
+  fn generate_maze(
+    &self, width: usize, height: usize, _orientation: bool, _show_generation: Option<ShowGeneration>,
+    _rng: &mut StdRng) -> Result<(Array2<u8>, usize), MazeError> {
+    let codes = Array2::from_shape_fn((height, width), |(_, x)| if x == 0 {1} else if x +1 == width {2} else {3});
+    Ok((codes, height))
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Mazes of less than two nodes are rejected, be they generated via "Maze::generate" or directly via a generator
+// ----------------------------------------------------------------
+#[test]
+fn too_small_mazes_are_rejected() {
+  let mut rng: StdRng = SeedableRng::seed_from_u64(0);
+  assert_eq!(
+    Maze::generate(1, 1, true, &Kruskal, None, &mut rng).unwrap_err(), MazeError::TooSmall{width: 1, height: 1});
+  assert_eq!(Kruskal.generate_maze(0, 5, true, None, &mut rng).unwrap_err(), MazeError::TooSmall{width: 0, height: 5});
+}
+
+
+// ----------------------------------------------------------------
+// Unreadable mazes and constraints are rejected, whatever their format
+// ----------------------------------------------------------------
+#[test]
+fn unreadable_inputs_are_rejected() {
+  assert!(matches!(Maze::from_json("{\"width\": 3"), Err(MazeError::Parse(_))));
+  assert!(matches!(Maze::from_hex_string("not a maze"), Err(MazeError::Parse(_))));
+  assert!(matches!(Maze::from_ascii("not a maze"), Err(MazeError::Parse(_))));
+  assert!(matches!("turns>>3".parse::<Constraint>(), Err(MazeError::Parse(_))));
+}
+
+
+// ----------------------------------------------------------------
+// A generator may yield a maze whose goal cannot be reached from the entrance, which is reported while measuring it
+// ----------------------------------------------------------------
+#[test]
+fn unreachable_goal_is_reported() {
+  let mut rng: StdRng = SeedableRng::seed_from_u64(0);
+  let constraints = ["turns>=1".parse::<Constraint>().unwrap()];
+  assert_eq!(
+    Maze::generate_with_constraints(3, 2, false, &Corridors, None, &constraints, 5, &mut rng).unwrap_err(),
+    MazeError::UnreachableGoal);
+}
+
+
+// ----------------------------------------------------------------
+// Nodes outside of the maze are rejected: placed entrance and goal, origin of a distance map or a heat map, and...
+// ...path of a solution found for another maze
+// ----------------------------------------------------------------
+#[test]
+fn nodes_out_of_bounds_are_rejected() {
+  let mut rng: StdRng = SeedableRng::seed_from_u64(0);
+  let mut maze = Maze::generate(3, 3, false, &Kruskal, None, &mut rng).unwrap();
+  let pos = Pos{x: 3, y: 1};
+  assert_eq!(
+    maze.place(&Placement::Fixed{entrance: Pos{x: 0, y: 0}, goal: pos}, &mut rng).unwrap_err(),
+    MazeError::OutOfBounds{pos});
+  assert_eq!(maze.distances_from(&pos).unwrap_err(), MazeError::OutOfBounds{pos});
+  let options = SvgOptions{distance_origin: Some(pos), ..SvgOptions::default()};
+  assert_eq!(maze.to_svg(None, &options).unwrap_err(), MazeError::OutOfBounds{pos});
+  let options = RasterOptions{distance_origin: Some(pos), ..RasterOptions::default()};
+  assert_eq!(maze.to_image(None, &options).unwrap_err(), MazeError::OutOfBounds{pos});
+
+  let larger_maze = Maze::generate(6, 6, false, &Kruskal, None, &mut rng).unwrap();
+  let solution = larger_maze.solve(&BreadthFirst, None, &mut rng).unwrap();
+  assert!(matches!(maze.to_svg(Some(&solution), &SvgOptions::default()), Err(MazeError::OutOfBounds{..})));
+  assert!(matches!(maze.to_image(Some(&solution), &RasterOptions::default()), Err(MazeError::OutOfBounds{..})));
+}
+
+
+// ----------------------------------------------------------------
+// An inconsistent maze (e.g. with its entrance outside of the maze) cannot be drawn
+// ----------------------------------------------------------------
+#[test]
+fn inconsistent_mazes_are_not_drawn() {
+  let mut rng: StdRng = SeedableRng::seed_from_u64(0);
+  let mut maze = Maze::generate(3, 3, false, &Kruskal, None, &mut rng).unwrap();
+  maze.pos_entrance = Pos{x: 0, y: 7};
+  let error = MazeError::Inconsistent(vec![ValidationError::MisplacedEntrance{pos: maze.pos_entrance}]);
+  assert_eq!(maze.to_svg(None, &SvgOptions::default()).unwrap_err(), error);
+  assert_eq!(maze.to_image(None, &RasterOptions::default()).unwrap_err(), error);
+}
+
+
+// ----------------------------------------------------------------
+// Images beyond the size limits of their format are rejected instead of being silently truncated
+// ----------------------------------------------------------------
+#[test]
+fn oversized_images_are_rejected() {
+  let (width, height) = (u16::MAX as usize +1, 2);
+  let image = RgbImage{width, height, pixels: vec![255; 3*width*height]};
+  let mut animation = Animation::new(100);
+  animation.add_frame(image.clone());
+  assert_eq!(
+    animation.write_gif(Vec::new()), Err(MazeError::ImageTooLarge{width, height, max_size: u16::MAX as usize}));
+  assert_eq!(image.write_png(Vec::new()), Ok(()));
+}
@@ -0,0 +1,80 @@
+use ndarray::Array2;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use random_maze::{Maze, MazeGenerator, Pos};
+use random_maze::generation::{AldousBroder, Eller, Kruskal, Prim, RecursiveBacktracker, RecursiveDivision, Wilson};
+
+
+// ----------------------------------------------------------------
+// Generate mazes of various sizes and orientations, and check that each one is perfect and consistent
+// ----------------------------------------------------------------
+fn check_generator_yields_perfect_mazes(generator: &dyn MazeGenerator) {
+  for seed in 0..30 {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed as u64);
+    let (width, height) = (1 + seed % 9, 2 + seed % 7);
+    let maze = Maze::generate(width, height, seed % 2 == 0, generator, None, &mut rng).unwrap();
+    assert_eq!(maze.validate(true), Ok(()), "{} on seed {}", generator.name(), seed);
+  }
+}
+
+
+#[test]
+fn recursive_division_yields_perfect_mazes() {
+  check_generator_yields_perfect_mazes(&RecursiveDivision);
+}
+
+
+#[test]
+fn prim_yields_perfect_mazes() {
+  check_generator_yields_perfect_mazes(&Prim);
+}
+
+
+#[test]
+fn kruskal_yields_perfect_mazes() {
+  check_generator_yields_perfect_mazes(&Kruskal);
+}
+
+
+#[test]
+fn wilson_yields_perfect_mazes() {
+  check_generator_yields_perfect_mazes(&Wilson);
+}
+
+
+#[test]
+fn aldous_broder_yields_perfect_mazes() {
+  check_generator_yields_perfect_mazes(&AldousBroder);
+}
+
+
+#[test]
+fn recursive_backtracker_yields_perfect_mazes() {
+  check_generator_yields_perfect_mazes(&RecursiveBacktracker);
+}
+
+
+#[test]
+fn eller_yields_perfect_mazes() {
+  check_generator_yields_perfect_mazes(&Eller);
+}
+
+
+// ----------------------------------------------------------------
+// The rows streamed by Eller's algorithm for a given height form a perfect and consistent maze on their own
+// ----------------------------------------------------------------
+#[test]
+fn eller_rows_form_perfect_mazes() {
+  for seed in 0..30 {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed as u64);
+    let (width, height) = (1 + seed % 9, 2 + seed % 7);
+    let rows: Vec<u8> = Eller::rows(width, Some(height), &mut rng).flatten().collect();
+    assert_eq!(rows.len(), width*height, "seed {}", seed);
+    let maze = Maze{
+      width, height, codes: Array2::from_shape_vec((height, width), rows).unwrap(), pos_entrance: Pos{x: 0, y: 0},
+      pos_goal: Pos{x: width -1, y: height -1}, orientation: false, generator: String::from("eller"), seed: None,
+      nb_iter_create: height};
+    assert_eq!(maze.validate(true), Ok(()), "seed {}", seed);
+  }
+}
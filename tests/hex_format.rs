@@ -0,0 +1,89 @@
+use std::process::Command;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use random_maze::{Maze, MazeGenerator};
+use random_maze::generation::{Eller, Kruskal, Prim, RecursiveDivision, Wilson};
+
+
+// ----------------------------------------------------------------
+// Writing a maze in the compact hexadecimal format and reading it back yields the same maze
+// ----------------------------------------------------------------
+#[test]
+fn hex_string_round_trip() {
+  let generators: [&dyn MazeGenerator; 5] = [&RecursiveDivision, &Prim, &Kruskal, &Wilson, &Eller];
+  for (seed, generator) in generators.iter().cycle().take(50).enumerate() {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed as u64);
+    let (width, height) = (2 + seed % 9, 1 + seed % 6);
+    let mut maze = Maze::generate(width, height, seed % 2 == 0, *generator, None, &mut rng).unwrap();
+    maze.braid(0.5, &mut rng);
+
+    let maze_read = Maze::from_hex_string(&maze.to_hex_string()).unwrap();
+    assert_eq!(maze_read.codes, maze.codes);
+    assert_eq!(maze_read.pos_entrance, maze.pos_entrance);
+    assert_eq!(maze_read.pos_goal, maze.pos_goal);
+    assert_eq!(maze_read.orientation, maze.orientation);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// The rows of the compact hexadecimal format are the codes printed by the ascii renderer in the "[encoding]" view...
+// ...and the maze read back from the ascii-art is the maze read from the compact hexadecimal format
+// ----------------------------------------------------------------
+#[test]
+fn hex_string_matches_ascii_renderer() {
+  for seed in 0..10 {
+    let path = std::env::temp_dir().join(format!("random_maze_{}_{}.hex", std::process::id(), seed));
+    let output = Command::new(env!("CARGO_BIN_EXE_random-maze"))
+      .args(["--seed", &seed.to_string(), "--width", "9", "--height", "5", "--ascii", "plain", "--braid", "0.3"])
+      .arg("--output").arg(&path)
+      .output()
+      .unwrap();
+    assert!(output.status.success());
+    let printout = strip_color_codes(&String::from_utf8(output.stdout).unwrap());
+    let hex_string = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    // Codes printed in the nodes of the "[encoding]" view, row by row
+    let encoding: Vec<String> = printout.lines()
+      .skip_while(|line| !line.starts_with("[encoding]"))
+      .skip(1)
+      .take_while(|line| !line.is_empty())
+      .skip(1)
+      .step_by(2)
+      .map(|line| line.chars().skip(1).step_by(2).collect())
+      .collect();
+    let rows: Vec<String> = hex_string.lines().skip(1).map(String::from).collect();
+    assert_eq!(rows, encoding);
+
+    let maze_from_ascii = Maze::from_ascii(&printout).unwrap();
+    let maze_from_hex = Maze::from_hex_string(&hex_string).unwrap();
+    assert_eq!(maze_from_ascii.codes, maze_from_hex.codes);
+    assert_eq!(maze_from_ascii.pos_entrance, maze_from_hex.pos_entrance);
+    assert_eq!(maze_from_ascii.pos_goal, maze_from_hex.pos_goal);
+    assert_eq!(maze_from_ascii.orientation, maze_from_hex.orientation);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// Remove the ANSI escape sequences used to print symbols in color
+// ----------------------------------------------------------------
+fn strip_color_codes(text: &str) -> String {
+  let mut stripped = String::with_capacity(text.len());
+  let mut chars = text.chars();
+  while let Some(c) = chars.next() {
+    if c == '\x1b' {
+      for c in chars.by_ref() {
+        if c.is_ascii_alphabetic() {
+          break;
+        }
+      }
+    } else {
+      stripped.push(c);
+    }
+  }
+  stripped
+}
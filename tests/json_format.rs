@@ -0,0 +1,66 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use random_maze::{Maze, MazeError, MazeGenerator, Placement, Pos, ValidationError};
+use random_maze::generation::{Kruskal, Prim, RecursiveDivision, Wilson};
+
+
+// ----------------------------------------------------------------
+// Writing a maze in the JSON format and reading it back yields the same maze
+// ----------------------------------------------------------------
+#[test]
+fn json_round_trip() {
+  let generators: [&dyn MazeGenerator; 4] = [&RecursiveDivision, &Prim, &Kruskal, &Wilson];
+  for (seed, generator) in generators.iter().cycle().take(40).enumerate() {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed as u64);
+    let (width, height) = (2 + seed % 9, 1 + seed % 6);
+    let mut maze = Maze::generate(width, height, seed % 2 == 0, *generator, None, &mut rng).unwrap();
+    maze.braid(0.5, &mut rng);
+    maze.seed = Some(seed as u64);
+    if seed % 3 == 0 {
+      maze.place(&Placement::Border, &mut rng).unwrap();
+    }
+
+    let maze_read = Maze::from_json(&maze.to_json()).unwrap();
+    assert_eq!(maze_read.codes, maze.codes, "seed {}", seed);
+    assert_eq!(maze_read.pos_entrance, maze.pos_entrance, "seed {}", seed);
+    assert_eq!(maze_read.pos_goal, maze.pos_goal, "seed {}", seed);
+    assert_eq!(maze_read.orientation, maze.orientation, "seed {}", seed);
+    assert_eq!(maze_read.generator, maze.generator, "seed {}", seed);
+    assert_eq!(maze_read.seed, maze.seed, "seed {}", seed);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// A door open from one side only is reported as such
+// ----------------------------------------------------------------
+#[test]
+fn json_with_asymmetric_door_is_rejected() {
+  let json = get_json(3, 1, &["112"]);
+  assert_eq!(
+    Maze::from_json(&json).unwrap_err(),
+    MazeError::Inconsistent(vec![ValidationError::AsymmetricDoor{pos_a: Pos{x: 0, y: 0}, pos_b: Pos{x: 1, y: 0}}]));
+}
+
+
+// ----------------------------------------------------------------
+// Rows that do not match the width are rejected before any memory is allocated for the codes (a huge width...
+// ...would otherwise abort the process)
+// ----------------------------------------------------------------
+#[test]
+fn json_with_bogus_width_is_rejected() {
+  let json = get_json(usize::MAX, 2, &["12", "12"]);
+  assert!(matches!(Maze::from_json(&json), Err(MazeError::Parse(_))));
+}
+
+
+// ----------------------------------------------------------------
+// Horizontal maze in the JSON format, with the entrance and goal in the top-left and top-right corners
+// ----------------------------------------------------------------
+fn get_json(width: usize, height: usize, rows: &[&str]) -> String {
+  format!(
+    r#"{{"width": {}, "height": {}, "orientation": "horizontal", "entrance": {{"x": 0, "y": 0}},
+    "goal": {{"x": {}, "y": 0}}, "seed": null, "generator": "manual", "codes": {:?}}}"#,
+    width, height, width.saturating_sub(1), rows)
+}
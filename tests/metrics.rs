@@ -0,0 +1,36 @@
+use ndarray::Array2;
+
+use random_maze::{Maze, Pos};
+use random_maze::metrics::compute_metrics;
+
+
+// ----------------------------------------------------------------
+// Metrics of a hand-built 3x3 maze, whose shortest path goes along the top side, then down the right side:
+// E → · → ·
+//         ↓
+// · - · - ·    (junction on the right side)
+// |       ↓
+// · - ·   · → G
+// ----------------------------------------------------------------
+#[test]
+fn metrics_of_hand_built_maze() {
+  let codes = Array2::from_shape_vec((3, 3), vec![0x1, 0x3, 0x6, 0x5, 0x3, 0xE, 0x9, 0x2, 0x8]).unwrap();
+  let maze = Maze{
+    width: 3, height: 3, codes, pos_entrance: Pos{x: 0, y: 0}, pos_goal: Pos{x: 2, y: 2}, orientation: true,
+    generator: String::from("manual"), seed: None, nb_iter_create: 0};
+  assert_eq!(maze.validate(true), Ok(()));
+  let path = [Pos{x: 0, y: 0}, Pos{x: 1, y: 0}, Pos{x: 2, y: 0}, Pos{x: 2, y: 1}, Pos{x: 2, y: 2}];
+
+  let metrics = compute_metrics(&maze, &path).unwrap();
+  assert_eq!(metrics.nb_nodes, 9);
+  assert_eq!(metrics.nb_dead_ends, 3); // entrance, goal, and bottom-center node
+  assert_eq!(metrics.nb_junctions, 1); // right-center node
+  assert_eq!(metrics.corridor_lengths, vec![0, 1, 0, 1, 1]); // to the goal (1), from the entrance (3), and around (4)
+  assert_eq!(metrics.nb_corridors(), 3);
+  assert_eq!(metrics.mean_corridor_length(), 8.0/3.0);
+  assert_eq!(metrics.solution_length, 5);
+  assert_eq!(metrics.solution_ratio, 5.0/9.0);
+  assert_eq!(metrics.nb_turns, 2); // rightwards through the entrance, downwards, and rightwards through the goal
+  assert_eq!(metrics.river_factor, 5.0/9.0);
+  assert_eq!(metrics.branching_factor, 6.0/5.0); // two ways forward at the junction, one elsewhere
+}
@@ -0,0 +1,44 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use random_maze::{Maze, MazeError, Placement};
+use random_maze::generation::Kruskal;
+
+
+// ----------------------------------------------------------------
+// With "Placement::SameSide", the entrance and the goal open on the same outer wall, even from a corner
+// ----------------------------------------------------------------
+#[test]
+fn same_side_gates_open_on_the_same_side() {
+  for seed in 0..100 {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed as u64);
+    let (width, height) = (2 + seed % 7, 2 + seed % 5);
+    let mut maze = Maze::generate(width, height, seed % 2 == 0, &Kruskal, None, &mut rng).unwrap();
+    maze.place(&Placement::SameSide, &mut rng).unwrap();
+    assert!(maze.entrance_gate().side.is_some(), "seed {}", seed);
+    assert_eq!(maze.goal_gate().side, maze.entrance_gate().side, "seed {}", seed);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// A single column (horizontal maze) or row (vertical maze) lies on both opposed sides: the goal then opens on the...
+// ...side opposite to the entrance, so "Placement::SameSide" is rejected, unlike "Placement::OppositeSides"
+// ----------------------------------------------------------------
+#[test]
+fn same_side_is_rejected_in_single_column_or_row() {
+  for seed in 0..20 {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed as u64);
+    let length = 2 + seed % 6;
+    for (width, height, orientation) in [(1, length, true), (length, 1, false), (length, 1, true), (1, length, false)] {
+      let mut maze = Maze::generate(width, height, orientation, &Kruskal, None, &mut rng).unwrap();
+      assert!(
+        matches!(maze.place(&Placement::SameSide, &mut rng), Err(MazeError::InvalidPlacement(_))),
+        "{}x{} maze on seed {}", width, height, seed);
+
+      maze.place(&Placement::OppositeSides, &mut rng).unwrap();
+      let (side_entrance, side_goal) = (maze.entrance_gate().side.unwrap(), maze.goal_gate().side.unwrap());
+      assert_eq!(side_goal, side_entrance.opposite(), "{}x{} maze on seed {}", width, height, seed);
+    }
+  }
+}
@@ -0,0 +1,37 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use random_maze::{Maze, MazeGenerator, Openings, Solver};
+use random_maze::distance::get_distance_map;
+use random_maze::generation::{AldousBroder, Eller, Kruskal, Prim, RecursiveBacktracker, RecursiveDivision, Wilson};
+use random_maze::solving::{AStar, BreadthFirst, DepthFirst, Dijkstra, RandomFront};
+
+
+// ----------------------------------------------------------------
+// In a maze with loops, every search strategy returns a path as short as the distance from the entrance to the...
+// ...goal found via a breadth-first traversal, and this path only moves in-between connected neighbors
+// ----------------------------------------------------------------
+#[test]
+fn solvers_return_shortest_path_in_braided_mazes() {
+  let generators: [&dyn MazeGenerator; 7] = [
+    &RecursiveDivision, &Prim, &Kruskal, &Wilson, &AldousBroder, &RecursiveBacktracker, &Eller];
+  let solvers: [&dyn Solver; 5] = [&RandomFront, &BreadthFirst, &DepthFirst, &Dijkstra, &AStar];
+  for (seed, generator) in generators.iter().cycle().take(140).enumerate() {
+    let mut rng: StdRng = SeedableRng::seed_from_u64(seed as u64);
+    let (width, height) = (2 + seed % 13, 2 + seed % 11);
+    let mut maze = Maze::generate(width, height, seed % 2 == 0, *generator, None, &mut rng).unwrap();
+    maze.braid(0.25 + 0.25*(seed % 4) as f64, &mut rng);
+
+    let distances = get_distance_map(&maze.codes, &maze.pos_entrance);
+    let distance_goal = distances[[maze.pos_goal.y, maze.pos_goal.x]].unwrap();
+    for solver in solvers.iter() {
+      let solution = maze.solve(*solver, None, &mut rng).unwrap();
+      assert_eq!(solution.path_length, distance_goal +1, "{} on seed {}", solver.name(), seed);
+      assert_eq!(solution.path.first(), Some(&maze.pos_entrance));
+      assert_eq!(solution.path.last(), Some(&maze.pos_goal));
+      for (pos, pos_next) in solution.path.iter().zip(solution.path.iter().skip(1)) {
+        assert!(Openings::from_bits_truncate(maze.codes[[pos.y, pos.x]]).neighbors(pos).contains(pos_next));
+      }
+    }
+  }
+}
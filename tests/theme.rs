@@ -0,0 +1,68 @@
+use random_maze::{MazeError, Theme};
+
+
+// ----------------------------------------------------------------
+// The built-in themes, written in TOML, are read back as is
+// ----------------------------------------------------------------
+#[test]
+fn builtin_themes_are_read_from_toml() {
+  for (name, theme) in Theme::builtins() {
+    assert_eq!(Theme::from_toml(&get_toml(&theme)), Ok(theme), "{} theme", name);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// The roles that are not given are taken from the plain theme
+// ----------------------------------------------------------------
+#[test]
+fn missing_roles_are_taken_from_plain_theme() {
+  let theme = Theme::from_toml("[goal]\nsymbol = \"X\"\ncolor = \"bright red\"\n").unwrap();
+  assert_eq!(theme.goal.symbol, "X");
+  assert_eq!(theme.goal.color, Some(colored::Color::BrightRed));
+  assert_eq!(Theme{goal: Theme::plain().goal, ..theme}, Theme::plain());
+}
+
+
+// ----------------------------------------------------------------
+// A symbol that is not a single character would break the layout of the maze
+// ----------------------------------------------------------------
+#[test]
+fn symbols_of_several_characters_are_rejected() {
+  for symbol in ["", "##", "■■"] {
+    assert_eq!(
+      Theme::from_toml(&format!("[wall]\nsymbol = \"{}\"\n", symbol)),
+      Err(MazeError::Parse(String::from("the `wall` symbol must be a single character"))),
+      "symbol `{}`", symbol);
+  }
+}
+
+
+// ----------------------------------------------------------------
+// The hexadecimal codes go from 1 to F: exactly fifteen digits are expected
+// ----------------------------------------------------------------
+#[test]
+fn hex_digits_other_than_fifteen_are_rejected() {
+  for digits in ["123456789ABCDE", "0123456789ABCDEF", ""] {
+    assert_eq!(
+      Theme::from_toml(&format!("[hex]\nsymbol = \"{}\"\n", digits)),
+      Err(MazeError::Parse(String::from("the `hex` symbol must hold fifteen digits (for the codes 1 to F)"))),
+      "digits `{}`", digits);
+  }
+  assert!(Theme::from_toml("[hex]\nsymbol = \"123456789abcdef\"\n").is_ok());
+}
+
+
+// ----------------------------------------------------------------
+// Write a theme in TOML, with one table per role (the built-in themes only use colors with single-word names)
+// ----------------------------------------------------------------
+fn get_toml(theme: &Theme) -> String {
+  let mut toml = format!("thin_walls = {}\n", theme.thin_walls);
+  for (role, glyph) in theme.roles().into_iter().chain([("hex", &theme.hex)]) {
+    toml += &format!("\n[{}]\nsymbol = \"{}\"\n", role, glyph.symbol);
+    if let Some(color) = glyph.color {
+      toml += &format!("color = \"{}\"\n", format!("{:?}", color).to_lowercase());
+    }
+  }
+  toml
+}
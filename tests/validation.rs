@@ -0,0 +1,91 @@
+use ndarray::Array2;
+
+use random_maze::{Maze, Pos, ValidationError};
+
+
+// ----------------------------------------------------------------
+// A door open from one side only
+// ----------------------------------------------------------------
+#[test]
+fn asymmetric_door_is_reported() {
+  let maze = get_maze(&["112"], Pos{x: 0, y: 0}, Pos{x: 2, y: 0});
+  assert_eq!(
+    maze.validate(false),
+    Err(vec![ValidationError::AsymmetricDoor{pos_a: Pos{x: 0, y: 0}, pos_b: Pos{x: 1, y: 0}}]));
+}
+
+
+// ----------------------------------------------------------------
+// A move through the outer walls
+// ----------------------------------------------------------------
+#[test]
+fn out_of_bounds_move_is_reported() {
+  let maze = get_maze(&["32"], Pos{x: 0, y: 0}, Pos{x: 1, y: 0});
+  assert_eq!(maze.validate(false), Err(vec![ValidationError::OutOfBoundsMove{pos: Pos{x: 0, y: 0}, code: 3}]));
+}
+
+
+// ----------------------------------------------------------------
+// A fully-closed node, which is also cut off from the rest of the maze
+// ----------------------------------------------------------------
+#[test]
+fn zero_code_is_reported() {
+  let maze = get_maze(&["120"], Pos{x: 0, y: 0}, Pos{x: 1, y: 0});
+  assert_eq!(
+    maze.validate(false),
+    Err(vec![
+      ValidationError::InvalidCode{pos: Pos{x: 2, y: 0}, code: 0},
+      ValidationError::DisconnectedComponents{nb_components: 2}]));
+}
+
+
+// ----------------------------------------------------------------
+// Two rows with no door in-between
+// ----------------------------------------------------------------
+#[test]
+fn disconnected_components_are_reported() {
+  let maze = get_maze(&["12", "12"], Pos{x: 0, y: 0}, Pos{x: 1, y: 1});
+  assert_eq!(maze.validate(false), Err(vec![ValidationError::DisconnectedComponents{nb_components: 2}]));
+}
+
+
+// ----------------------------------------------------------------
+// An entrance and a goal outside of the maze
+// ----------------------------------------------------------------
+#[test]
+fn misplaced_entrance_and_goal_are_reported() {
+  let maze = get_maze(&["12"], Pos{x: 2, y: 0}, Pos{x: 0, y: 5});
+  assert_eq!(
+    maze.validate(false),
+    Err(vec![
+      ValidationError::MisplacedEntrance{pos: Pos{x: 2, y: 0}},
+      ValidationError::MisplacedGoal{pos: Pos{x: 0, y: 5}}]));
+}
+
+
+// ----------------------------------------------------------------
+// A loop is only reported when the maze is expected to be perfect
+// ----------------------------------------------------------------
+#[test]
+fn cycles_are_reported_if_perfect_maze_expected() {
+  let maze = get_maze(&["56", "9A"], Pos{x: 0, y: 0}, Pos{x: 1, y: 1});
+  assert_eq!(maze.validate(false), Ok(()));
+  assert_eq!(maze.validate(true), Err(vec![ValidationError::Cycles{nb_extra_doors: 1}]));
+
+  let maze = get_maze(&["16", "1A"], Pos{x: 0, y: 0}, Pos{x: 1, y: 1});
+  assert_eq!(maze.validate(true), Ok(()));
+}
+
+
+// ----------------------------------------------------------------
+// Maze given by the hexadecimal codes of its rows, without any check
+// ----------------------------------------------------------------
+fn get_maze(rows: &[&str], pos_entrance: Pos, pos_goal: Pos) -> Maze {
+  let (width, height) = (rows[0].len(), rows.len());
+  let codes: Vec<u8> = rows.iter()
+    .flat_map(|row| row.chars().map(|digit| digit.to_digit(16).unwrap() as u8))
+    .collect();
+  Maze{
+    width, height, codes: Array2::from_shape_vec((height, width), codes).unwrap(), pos_entrance, pos_goal,
+    orientation: true, generator: String::from("manual"), seed: None, nb_iter_create: 0}
+}